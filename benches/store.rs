@@ -0,0 +1,48 @@
+//! Benchmarks for building and querying a `KnownValuesStore`.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use known_values::{KnownValue, KnownValuesStore};
+
+const ENTRY_COUNT: u64 = 50_000;
+const UNNAMED_COUNT: u64 = 5_000;
+
+fn registry() -> Vec<KnownValue> {
+    (0..ENTRY_COUNT)
+        .map(|codepoint| {
+            if codepoint < UNNAMED_COUNT {
+                KnownValue::new(codepoint)
+            } else {
+                KnownValue::new_with_name(
+                    codepoint,
+                    format!("value{codepoint}"),
+                )
+            }
+        })
+        .collect()
+}
+
+fn construction(c: &mut Criterion) {
+    let values = registry();
+    c.bench_function("new 50k", |b| {
+        b.iter_batched(
+            || values.clone(),
+            KnownValuesStore::new,
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn lookup(c: &mut Criterion) {
+    let store = KnownValuesStore::new(registry());
+    c.bench_function("known_value_named", |b| {
+        b.iter(|| store.known_value_named(black_box("value25001")))
+    });
+    c.bench_function("get", |b| b.iter(|| store.get(black_box(25_001))));
+}
+
+criterion_group!(benches, construction, lookup);
+criterion_main!(benches);
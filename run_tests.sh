@@ -28,3 +28,8 @@ section "No Default Features"
 cargo test --no-default-features > /dev/null
 
 test_only_features "directory-loading"
+
+test_additional_features "jsonc"
+test_additional_features "jsonschema"
+test_additional_features "archive"
+test_additional_features "perfect-hash"
@@ -0,0 +1,139 @@
+use super::{known_value::KnownValue, known_value_store::KnownValuesStore};
+
+/// A type that can be resolved to a [`KnownValue`].
+///
+/// This lets APIs accept a codepoint, a `KnownValue`, or an assigned name
+/// through a single `impl AsKnownValue` parameter.
+///
+/// Codepoints and `KnownValue`s resolve without a store; when a store is
+/// given, a codepoint picks up the store's entry for it. Names can only be
+/// resolved through a store, so they yield `None` without one.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{AsKnownValue, KnownValue, KnownValuesStore};
+///
+/// fn codepoint(
+///     value: impl AsKnownValue,
+///     store: &KnownValuesStore,
+/// ) -> Option<u64> {
+///     value
+///         .as_known_value(Some(store))
+///         .map(|known_value| known_value.value())
+/// }
+///
+/// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+///
+/// assert_eq!(codepoint(4u64, &store), Some(4));
+/// assert_eq!(codepoint(known_values::IS_A, &store), Some(1));
+/// assert_eq!(codepoint("note", &store), Some(4));
+/// assert_eq!(codepoint("isA".to_string(), &store), Some(1));
+/// assert_eq!(codepoint("unknown", &store), None);
+///
+/// assert_eq!(4u64.as_known_value(Some(&store)).unwrap().name(), "note");
+/// assert_eq!(42u64.as_known_value(None), Some(KnownValue::new(42)));
+/// assert_eq!("note".as_known_value(None), None);
+/// ```
+pub trait AsKnownValue {
+    /// Resolves `self` to a KnownValue, consulting `store` if given.
+    fn as_known_value(
+        &self,
+        store: Option<&KnownValuesStore>,
+    ) -> Option<KnownValue>;
+}
+
+impl AsKnownValue for u64 {
+    fn as_known_value(
+        &self,
+        store: Option<&KnownValuesStore>,
+    ) -> Option<KnownValue> {
+        store
+            .and_then(|store| store.get(*self))
+            .cloned()
+            .or_else(|| Some(KnownValue::new(*self)))
+    }
+}
+
+impl AsKnownValue for KnownValue {
+    fn as_known_value(
+        &self,
+        _store: Option<&KnownValuesStore>,
+    ) -> Option<KnownValue> {
+        Some(self.clone())
+    }
+}
+
+impl AsKnownValue for str {
+    fn as_known_value(
+        &self,
+        store: Option<&KnownValuesStore>,
+    ) -> Option<KnownValue> {
+        store?.known_value_named(self).cloned()
+    }
+}
+
+impl AsKnownValue for String {
+    fn as_known_value(
+        &self,
+        store: Option<&KnownValuesStore>,
+    ) -> Option<KnownValue> {
+        self.as_str().as_known_value(store)
+    }
+}
+
+impl<T: AsKnownValue + ?Sized> AsKnownValue for &T {
+    fn as_known_value(
+        &self,
+        store: Option<&KnownValuesStore>,
+    ) -> Option<KnownValue> {
+        (**self).as_known_value(store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_known_value_with_and_without_store() {
+        let store = KnownValuesStore::new([
+            crate::IS_A,
+            KnownValue::new_with_name(1000u64, "custom".to_string()),
+        ]);
+
+        // u64
+        assert_eq!(1000u64.as_known_value(None).unwrap().assigned_name(), None);
+        assert_eq!(
+            1000u64.as_known_value(Some(&store)).unwrap().name(),
+            "custom"
+        );
+        assert_eq!(
+            7u64.as_known_value(Some(&store)).unwrap().assigned_name(),
+            None
+        );
+
+        // KnownValue keeps its own name, even if the store disagrees
+        let renamed = KnownValue::new_with_name(1u64, "type".to_string());
+        assert!(renamed.as_known_value(None).unwrap().is_exactly(&renamed));
+        assert!(
+            renamed
+                .as_known_value(Some(&store))
+                .unwrap()
+                .is_exactly(&renamed)
+        );
+
+        // &str
+        assert_eq!("custom".as_known_value(None), None);
+        assert_eq!(
+            "custom".as_known_value(Some(&store)),
+            Some(KnownValue::new(1000))
+        );
+        assert_eq!("missing".as_known_value(Some(&store)), None);
+
+        // String
+        let name = "isA".to_string();
+        assert_eq!(name.as_known_value(None), None);
+        assert_eq!(name.as_known_value(Some(&store)), Some(crate::IS_A));
+    }
+}
@@ -0,0 +1,122 @@
+use std::{fmt, ops::RangeInclusive};
+
+/// A block of the Known Values codepoint space, following the groups of the
+/// builtin registry.
+///
+/// Membership depends only on the codepoint, so values loaded from
+/// directories fall into the same category as builtins in the same range,
+/// whether or not a value is assigned there. Codepoints past the last block
+/// are [`Category::Unassigned`].
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{Category, KnownValue};
+///
+/// assert_eq!(known_values::SEED_TYPE.category(), Category::Cryptography);
+/// assert_eq!(KnownValue::new(77).category(), Category::XidPrivileges);
+/// assert_eq!(Category::Bitcoin.range(), 500..=599);
+/// assert_eq!(Category::Graphs.name(), "Graphs");
+/// assert_eq!(Category::of(100_000), Category::Unassigned);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Category {
+    /// General-purpose values, codepoints 0-49.
+    General,
+    /// Attachment metadata, codepoints 50-59.
+    Attachments,
+    /// XID document structure, codepoints 60-69.
+    XidDocuments,
+    /// XID privileges, codepoints 70-99.
+    XidPrivileges,
+    /// Gordian Envelope expressions, codepoints 100-199.
+    Expressions,
+    /// Cryptographic key and seed types, codepoints 200-299.
+    Cryptography,
+    /// Cryptocurrency assets, codepoints 300-399.
+    CryptocurrencyAssets,
+    /// Cryptocurrency networks, codepoints 400-499.
+    CryptocurrencyNetworks,
+    /// Bitcoin wallet values, codepoints 500-599.
+    Bitcoin,
+    /// Graph structure, codepoints 600-799.
+    Graphs,
+    /// Every codepoint past the last block.
+    Unassigned,
+}
+
+impl Category {
+    /// Every category, in codepoint order.
+    pub const ALL: [Category; 11] = [
+        Category::General,
+        Category::Attachments,
+        Category::XidDocuments,
+        Category::XidPrivileges,
+        Category::Expressions,
+        Category::Cryptography,
+        Category::CryptocurrencyAssets,
+        Category::CryptocurrencyNetworks,
+        Category::Bitcoin,
+        Category::Graphs,
+        Category::Unassigned,
+    ];
+
+    /// Returns the category containing `codepoint`.
+    pub const fn of(codepoint: u64) -> Self {
+        let mut i = 0;
+        while i < Self::ALL.len() {
+            if Self::ALL[i].contains(codepoint) {
+                return Self::ALL[i];
+            }
+            i += 1;
+        }
+        Category::Unassigned
+    }
+
+    /// Returns the codepoints in this category.
+    pub const fn range(self) -> RangeInclusive<u64> {
+        match self {
+            Category::General => 0..=49,
+            Category::Attachments => 50..=59,
+            Category::XidDocuments => 60..=69,
+            Category::XidPrivileges => 70..=99,
+            Category::Expressions => 100..=199,
+            Category::Cryptography => 200..=299,
+            Category::CryptocurrencyAssets => 300..=399,
+            Category::CryptocurrencyNetworks => 400..=499,
+            Category::Bitcoin => 500..=599,
+            Category::Graphs => 600..=799,
+            Category::Unassigned => 800..=u64::MAX,
+        }
+    }
+
+    /// Returns true if `codepoint` is in this category.
+    pub const fn contains(self, codepoint: u64) -> bool {
+        let range = self.range();
+        *range.start() <= codepoint && codepoint <= *range.end()
+    }
+
+    /// Returns the human-readable name of this category, e.g.
+    /// `"Cryptography"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Category::General => "General",
+            Category::Attachments => "Attachments",
+            Category::XidDocuments => "XID Documents",
+            Category::XidPrivileges => "XID Privileges",
+            Category::Expressions => "Expressions",
+            Category::Cryptography => "Cryptography",
+            Category::CryptocurrencyAssets => "Cryptocurrency Assets",
+            Category::CryptocurrencyNetworks => "Cryptocurrency Networks",
+            Category::Bitcoin => "Bitcoin",
+            Category::Graphs => "Graphs",
+            Category::Unassigned => "Unassigned",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
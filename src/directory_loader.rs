@@ -37,68 +37,328 @@
 //!
 //! Only the `entries` array with `codepoint` and `name` fields
 //! is required; other fields are optional.
+//!
+//! A file may also contain a bare array of entries at the top level, as
+//! emitted by tools that don't use the envelope format:
+//!
+//! ```json
+//! [
+//!   {"codepoint": 1000, "name": "myValue"}
+//! ]
+//! ```
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap, HashSet},
     fmt, fs, io,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
     sync::{
-        Mutex,
+        Arc, Mutex,
         atomic::{AtomicBool, Ordering},
     },
 };
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::KnownValue;
+use crate::{KnownValue, KnownValuesStore};
 
 /// A single entry in a known values JSON registry file.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct RegistryEntry {
     /// The unique numeric identifier for this known value.
     pub codepoint: u64,
     /// The canonical string name for this known value.
     pub name: String,
     /// The type of entry (e.g., "property", "class", "value").
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub entry_type: Option<String>,
     /// An optional URI reference for this known value.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub uri: Option<String>,
     /// An optional human-readable description.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Whether this entry is deprecated. Deprecated entries are still parsed
+    /// and validated, and can be excluded with
+    /// [`DirectoryConfig::skip_deprecated`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Display names keyed by language tag (e.g., "en" or "en-GB").
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub names_by_lang: BTreeMap<String, String>,
+}
+
+impl RegistryEntry {
+    /// Checks that the entry is well formed.
+    ///
+    /// The name must be an identifier: an ASCII letter or `_` followed by
+    /// ASCII letters, digits, or `_`. The codepoint must not be 0, which is
+    /// reserved for the unit value. The URI, if present, must have a valid
+    /// scheme followed by a non-empty remainder without whitespace.
+    ///
+    /// This lets entries parsed outside the directory loader be checked
+    /// before they are inserted into a store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{EntryError, RegistryEntry};
+    ///
+    /// let entry: RegistryEntry = serde_json::from_str(
+    ///     r#"{"codepoint": 1000, "name": "myValue",
+    ///         "uri": "https://example.com/myValue"}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(entry.validate(), Ok(()));
+    ///
+    /// let entry = RegistryEntry { codepoint: 0, ..entry };
+    /// assert_eq!(entry.validate(), Err(EntryError::ZeroCodepoint));
+    /// ```
+    pub fn validate(&self) -> Result<(), EntryError> {
+        if !is_identifier(&self.name) {
+            return Err(EntryError::InvalidName { name: self.name.clone() });
+        }
+        if self.codepoint == 0 {
+            return Err(EntryError::ZeroCodepoint);
+        }
+        if let Some(uri) = &self.uri
+            && !is_uri(uri)
+        {
+            return Err(EntryError::InvalidUri { uri: uri.clone() });
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if `name` is an ASCII letter or `_` followed by ASCII
+/// letters, digits, or `_`.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Returns true if `uri` has an RFC 3986 scheme followed by `:` and a
+/// non-empty remainder with no whitespace or control characters.
+fn is_uri(uri: &str) -> bool {
+    let Some((scheme, rest)) = uri.split_once(':') else {
+        return false;
+    };
+    let mut scheme_chars = scheme.chars();
+    scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme_chars
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
+        && !rest.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// Errors reported by [`RegistryEntry::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryError {
+    /// The name is not a valid identifier.
+    InvalidName {
+        /// The offending name.
+        name: String,
+    },
+    /// The codepoint is 0, which is reserved for the unit value.
+    ZeroCodepoint,
+    /// The URI is not syntactically valid.
+    InvalidUri {
+        /// The offending URI.
+        uri: String,
+    },
+}
+
+impl fmt::Display for EntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntryError::InvalidName { name } => {
+                write!(f, "Invalid entry name: \"{}\"", name)
+            }
+            EntryError::ZeroCodepoint => {
+                write!(f, "Codepoint 0 is reserved for the unit value")
+            }
+            EntryError::InvalidUri { uri } => {
+                write!(f, "Invalid entry URI: \"{}\"", uri)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EntryError {}
+
+/// Converts a registry entry into a KnownValue, carrying over its entry type,
+/// URI, description, deprecation, and localized names.
+impl From<RegistryEntry> for KnownValue {
+    fn from(entry: RegistryEntry) -> Self {
+        let mut known_value =
+            KnownValue::new_with_name(entry.codepoint, entry.name);
+        if let Some(entry_type) = entry.entry_type {
+            known_value = known_value.with_entry_type(entry_type);
+        }
+        if let Some(uri) = entry.uri {
+            known_value = known_value.with_uri(uri);
+        }
+        if let Some(description) = entry.description {
+            known_value = known_value.with_description(description);
+        }
+        if entry.deprecated {
+            known_value = known_value.with_deprecated(true);
+        }
+        for (lang, name) in entry.names_by_lang {
+            known_value = known_value.with_localized_name(lang, name);
+        }
+        known_value
+    }
 }
 
 /// Metadata about the ontology or registry source.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct OntologyInfo {
     /// The name of this registry or ontology.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// The source URL for this registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub source_url: Option<String>,
     /// The starting codepoint for entries in this registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub start_code_point: Option<u64>,
     /// The processing strategy used to generate this registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub processing_strategy: Option<String>,
 }
 
 /// Root structure of a known values JSON registry file.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct RegistryFile {
+    /// An optional reference to the JSON Schema this file conforms to.
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// Metadata about this registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ontology: Option<OntologyInfo>,
     /// Information about how this file was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub generated: Option<GeneratedInfo>,
     /// The known value entries in this registry.
     pub entries: Vec<RegistryEntry>,
     /// Statistics about this registry (ignored during parsing).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub statistics: Option<serde_json::Value>,
+    /// Unrecognized top-level fields, retained so that files written in a
+    /// newer format re-serialize without loss.
+    ///
+    /// Kept sorted by key so that canonical output stays deterministic.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl RegistryFile {
+    /// Serializes the registry in a canonical form, suitable for normalizing
+    /// hand-edited files and producing meaningful diffs.
+    ///
+    /// Entries are sorted by codepoint, fields are emitted in a fixed order,
+    /// absent optional fields are omitted, and the output is indented with two
+    /// spaces and ends with a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::RegistryFile;
+    ///
+    /// let registry: RegistryFile = serde_json::from_str(
+    ///     r#"{"entries": [
+    ///         {"name": "second", "codepoint": 2},
+    ///         {"type": "property", "name": "first", "codepoint": 1}
+    ///     ]}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     registry.to_canonical_json(),
+    ///     r#"{
+    ///   "entries": [
+    ///     {
+    ///       "codepoint": 1,
+    ///       "name": "first",
+    ///       "type": "property"
+    ///     },
+    ///     {
+    ///       "codepoint": 2,
+    ///       "name": "second"
+    ///     }
+    ///   ]
+    /// }
+    /// "#
+    /// );
+    /// ```
+    pub fn to_canonical_json(&self) -> String {
+        self.to_json_with(SortOrder::ByCodepoint)
+    }
+
+    /// Serializes the registry like [`RegistryFile::to_canonical_json`], but
+    /// with the entries in the given order.
+    ///
+    /// Entries keep the order they had in the parsed file, so
+    /// [`SortOrder::AsGiven`] preserves a hand-maintained layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{RegistryFile, SortOrder};
+    ///
+    /// let registry: RegistryFile = serde_json::from_str(
+    ///     r#"{"entries": [
+    ///         {"codepoint": 2, "name": "alpha"},
+    ///         {"codepoint": 1, "name": "beta"}
+    ///     ]}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let json = registry.to_json_with(SortOrder::AsGiven);
+    /// assert!(json.find("alpha").unwrap() < json.find("beta").unwrap());
+    ///
+    /// let json = registry.to_json_with(SortOrder::ByCodepoint);
+    /// assert!(json.find("beta").unwrap() < json.find("alpha").unwrap());
+    /// ```
+    pub fn to_json_with(&self, order: SortOrder) -> String {
+        let mut sorted = self.clone();
+        match order {
+            SortOrder::ByCodepoint => {
+                sorted.entries.sort_by_key(|entry| entry.codepoint)
+            }
+            SortOrder::ByName => sorted.entries.sort_by(|a, b| {
+                a.name.cmp(&b.name).then(a.codepoint.cmp(&b.codepoint))
+            }),
+            SortOrder::AsGiven => {}
+        }
+        let mut json = serde_json::to_string_pretty(&sorted)
+            .expect("registry files always serialize to JSON");
+        json.push('\n');
+        json
+    }
+}
+
+/// The order in which [`RegistryFile::to_json_with`] emits entries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Entries sorted by codepoint, as in
+    /// [`RegistryFile::to_canonical_json`].
+    #[default]
+    ByCodepoint,
+    /// Entries sorted by name, with ties broken by codepoint.
+    ByName,
+    /// Entries in the order they appear in the registry.
+    AsGiven,
 }
 
 /// Information about how a registry file was generated.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GeneratedInfo {
     /// The tool used to generate this registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tool: Option<String>,
 }
 
@@ -114,6 +374,61 @@ pub enum LoadError {
         /// The underlying JSON error.
         error: serde_json::Error,
     },
+    /// A registry file was empty or contained only whitespace.
+    EmptyFile {
+        /// The empty file.
+        file: PathBuf,
+    },
+    /// A registry file is not valid UTF-8.
+    Encoding {
+        /// The file with invalid content.
+        file: PathBuf,
+    },
+    /// Two distinct codepoints were loaded with the same assigned name.
+    ///
+    /// Only reported when [`DirectoryConfig::allow_duplicate_names`] is
+    /// disabled.
+    DuplicateName {
+        /// The shared name.
+        name: String,
+        /// The codepoint that was loaded first and kept.
+        codepoint_a: u64,
+        /// The codepoint that was loaded later and skipped.
+        codepoint_b: u64,
+    },
+    /// A configured search path exists but is not a directory.
+    NotADirectory {
+        /// The offending path.
+        path: PathBuf,
+    },
+    /// A directory or file could not be read due to insufficient
+    /// permissions.
+    PermissionDenied {
+        /// The unreadable path.
+        path: PathBuf,
+    },
+    /// A directory manifest lists a path that is absolute or escapes the
+    /// manifest's directory.
+    ///
+    /// Only paths relative to the manifest's directory, without `..`
+    /// components, are accepted. See [`DirectoryConfig::use_manifest`].
+    InvalidManifestEntry {
+        /// The manifest that lists the entry.
+        manifest: PathBuf,
+        /// The rejected entry, as listed.
+        entry: PathBuf,
+    },
+    /// A registry file does not conform to the registry JSON Schema.
+    ///
+    /// Only reported when the `jsonschema` feature is enabled. The variant
+    /// exists regardless, so that matching on [`LoadError`] does not depend
+    /// on which features other crates enable.
+    SchemaViolation {
+        /// The file that failed validation.
+        file: PathBuf,
+        /// A description of each violation, prefixed with its location.
+        errors: Vec<String>,
+    },
 }
 
 impl fmt::Display for LoadError {
@@ -123,6 +438,45 @@ impl fmt::Display for LoadError {
             LoadError::Json { file, error } => {
                 write!(f, "JSON parse error in {}: {}", file.display(), error)
             }
+            LoadError::EmptyFile { file } => {
+                write!(f, "Empty registry file: {}", file.display())
+            }
+            LoadError::Encoding { file } => {
+                write!(
+                    f,
+                    "Registry file is not valid UTF-8: {}",
+                    file.display()
+                )
+            }
+            LoadError::DuplicateName { name, codepoint_a, codepoint_b } => {
+                write!(
+                    f,
+                    "Duplicate name \"{}\" for codepoints {} and {}",
+                    name, codepoint_a, codepoint_b
+                )
+            }
+            LoadError::NotADirectory { path } => {
+                write!(f, "Not a directory: {}", path.display())
+            }
+            LoadError::PermissionDenied { path } => {
+                write!(f, "Permission denied: {}", path.display())
+            }
+            LoadError::InvalidManifestEntry { manifest, entry } => {
+                write!(
+                    f,
+                    "Invalid manifest entry {} in {}",
+                    entry.display(),
+                    manifest.display()
+                )
+            }
+            LoadError::SchemaViolation { file, errors } => {
+                write!(
+                    f,
+                    "Schema violation in {}: {}",
+                    file.display(),
+                    errors.join("; ")
+                )
+            }
         }
     }
 }
@@ -132,6 +486,13 @@ impl std::error::Error for LoadError {
         match self {
             LoadError::Io(e) => Some(e),
             LoadError::Json { error, .. } => Some(error),
+            LoadError::EmptyFile { .. } => None,
+            LoadError::Encoding { .. } => None,
+            LoadError::DuplicateName { .. } => None,
+            LoadError::NotADirectory { .. } => None,
+            LoadError::PermissionDenied { .. } => None,
+            LoadError::InvalidManifestEntry { .. } => None,
+            LoadError::SchemaViolation { .. } => None,
         }
     }
 }
@@ -140,6 +501,65 @@ impl From<io::Error> for LoadError {
     fn from(error: io::Error) -> Self { LoadError::Io(error) }
 }
 
+impl LoadError {
+    /// Classifies an I/O error encountered while reading `path`, reporting
+    /// permission problems as [`LoadError::PermissionDenied`] and content
+    /// that is not UTF-8 as [`LoadError::Encoding`].
+    fn from_io(path: &Path, error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::PermissionDenied => {
+                LoadError::PermissionDenied { path: path.to_path_buf() }
+            }
+            io::ErrorKind::InvalidData => {
+                LoadError::Encoding { file: path.to_path_buf() }
+            }
+            _ => LoadError::Io(error),
+        }
+    }
+}
+
+/// A codepoint collision resolved while merging loaded values.
+#[derive(Debug, Clone)]
+pub struct ValueOverride {
+    /// The value that was kept according to the [`MergeStrategy`].
+    pub kept: KnownValue,
+    /// The value that was discarded.
+    pub discarded: KnownValue,
+}
+
+/// Counts of how the values of a [`LoadResult`] were folded into a store by
+/// [`KnownValuesStore::merge_result`](crate::KnownValuesStore::merge_result).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MergeSummary {
+    /// Values whose codepoint was not yet in the store.
+    pub added: usize,
+    /// Values that replaced an existing value with the same codepoint.
+    pub overwritten: usize,
+    /// Values that were discarded in favor of an existing value.
+    pub skipped: usize,
+}
+
+/// A name assigned to more than one codepoint, as reported by
+/// [`LoadResult::dedup_names`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameCollision {
+    /// The shared name.
+    pub name: String,
+    /// The codepoints that carried the name, in ascending order.
+    pub codepoints: Vec<u64>,
+}
+
+/// How [`LoadResult::dedup_names`] resolves the collisions it finds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupStrategy {
+    /// Only report collisions, leaving every name unchanged.
+    #[default]
+    ReportOnly,
+    /// Keep the name on the lowest codepoint and give the others numbered
+    /// suffixes, e.g. `token_2`.
+    Suffix,
+}
+
 /// Result of a directory loading operation.
 #[derive(Debug, Default)]
 pub struct LoadResult {
@@ -147,8 +567,25 @@ pub struct LoadResult {
     pub values: HashMap<u64, KnownValue>,
     /// Files that were successfully processed.
     pub files_processed: Vec<PathBuf>,
+    /// Ontology metadata declared by loaded registry files, in load order.
+    pub ontologies: Vec<(PathBuf, OntologyInfo)>,
+    /// Codepoint collisions resolved while merging, in load order.
+    pub overrides: Vec<ValueOverride>,
     /// Non-fatal errors encountered during loading.
     pub errors: Vec<(PathBuf, LoadError)>,
+    /// Directory entries examined while looking for registry files,
+    /// including files that are not registry files. When a directory has a
+    /// manifest, each listed file counts once.
+    pub files_scanned: usize,
+    /// Registry files that were read and parsed successfully.
+    pub files_parsed: usize,
+    /// Entries from parsed files that were merged into
+    /// [`LoadResult::values`], including ones later overridden.
+    pub entries_loaded: usize,
+    /// Entries from parsed files that were left out, either because they
+    /// are deprecated and [`DirectoryConfig::skip_deprecated`] is set or
+    /// because their name is a [`LoadError::DuplicateName`].
+    pub entries_skipped: usize,
 }
 
 impl LoadResult {
@@ -165,19 +602,361 @@ impl LoadResult {
         self.values.into_values()
     }
 
+    /// Consumes the result and returns a new store holding the loaded known
+    /// values.
+    ///
+    /// Errors and other bookkeeping are discarded; check
+    /// [`LoadResult::has_errors`] first if they matter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, LoadResult};
+    ///
+    /// let mut result = LoadResult::default();
+    /// result.values.insert(
+    ///     1000,
+    ///     KnownValue::new_with_name(1000u64, "custom".to_string()),
+    /// );
+    ///
+    /// let store = result.into_store();
+    /// assert_eq!(store.codepoint_of("custom"), Some(1000));
+    /// ```
+    pub fn into_store(self) -> KnownValuesStore {
+        KnownValuesStore::new(self.into_values())
+    }
+
     /// Returns true if any errors occurred during loading.
     pub fn has_errors(&self) -> bool { !self.errors.is_empty() }
+
+    /// Returns the ontology metadata declared by a loaded registry file, if
+    /// any.
+    ///
+    /// `file` is matched against the paths recorded in
+    /// [`LoadResult::ontologies`].
+    pub fn ontology(&self, file: &Path) -> Option<&OntologyInfo> {
+        self.ontologies
+            .iter()
+            .find(|(path, _)| path == file)
+            .map(|(_, ontology)| ontology)
+    }
+
+    /// Returns the processing strategy a loaded registry file declares in its
+    /// ontology metadata, e.g. to decide how far to trust its entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// use known_values::{LoadResult, OntologyInfo};
+    ///
+    /// let mut result = LoadResult::default();
+    /// result.ontologies.push((
+    ///     PathBuf::from("generated.json"),
+    ///     OntologyInfo {
+    ///         name: Some("generated".to_string()),
+    ///         source_url: None,
+    ///         start_code_point: None,
+    ///         processing_strategy: Some("automated".to_string()),
+    ///     },
+    /// ));
+    ///
+    /// let file = Path::new("generated.json");
+    /// assert_eq!(result.processing_strategy(file), Some("automated"));
+    /// assert_eq!(result.processing_strategy(Path::new("other.json")), None);
+    /// ```
+    pub fn processing_strategy(&self, file: &Path) -> Option<&str> {
+        self.ontology(file)?.processing_strategy.as_deref()
+    }
+
+    /// Folds the result of another load into this one.
+    ///
+    /// Values are merged according to `strategy`, treating `other` as the
+    /// later load: under [`MergeStrategy::LastWins`] its values replace ours
+    /// when codepoints collide, and under [`MergeStrategy::FirstWins`] ours
+    /// are kept. Each collision is recorded in [`LoadResult::overrides`].
+    /// Processed files, ontology metadata, overrides, and errors from `other`
+    /// are appended.
+    ///
+    /// This supports incremental reloading, where only changed paths are
+    /// loaded again and merged into a previous result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, LoadResult, MergeStrategy};
+    ///
+    /// let mut previous = LoadResult::default();
+    /// previous
+    ///     .values
+    ///     .insert(1000, KnownValue::new_with_name(1000u64, "old".to_string()));
+    /// let mut fresh = LoadResult::default();
+    /// fresh
+    ///     .values
+    ///     .insert(1000, KnownValue::new_with_name(1000u64, "new".to_string()));
+    ///
+    /// previous.merge_from(fresh, MergeStrategy::LastWins);
+    /// assert_eq!(previous.values[&1000].name(), "new");
+    /// assert_eq!(previous.overrides.len(), 1);
+    /// ```
+    pub fn merge_from(&mut self, other: LoadResult, strategy: MergeStrategy) {
+        self.overrides.extend(other.overrides);
+        let mut values: Vec<_> = other.values.into_values().collect();
+        values.sort_by_key(KnownValue::value);
+        for value in values {
+            self.merge_value(value, strategy);
+        }
+        self.files_processed.extend(other.files_processed);
+        self.ontologies.extend(other.ontologies);
+        self.errors.extend(other.errors);
+        self.files_scanned += other.files_scanned;
+        self.files_parsed += other.files_parsed;
+        self.entries_loaded += other.entries_loaded;
+        self.entries_skipped += other.entries_skipped;
+    }
+
+    /// Finds names that are assigned to more than one codepoint and, with
+    /// [`DedupStrategy::Suffix`], renames all but one of them.
+    ///
+    /// Loading with [`DirectoryConfig::allow_duplicate_names`] (the default)
+    /// keeps every codepoint's name, so a name may be shared by values from
+    /// different registries. A store only resolves such a name to the value
+    /// inserted last, so this is the place to find and fix collisions.
+    ///
+    /// Collisions are reported sorted by name, each with its codepoints in
+    /// ascending order. When suffixing, the lowest codepoint keeps the name
+    /// and the others become `name_2`, `name_3`, and so on, skipping any
+    /// suffixed name that is already taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{DedupStrategy, KnownValue, LoadResult};
+    ///
+    /// let mut result = LoadResult::default();
+    /// for codepoint in [1000u64, 2000] {
+    ///     result.values.insert(
+    ///         codepoint,
+    ///         KnownValue::new_with_name(codepoint, "token".to_string()),
+    ///     );
+    /// }
+    ///
+    /// let collisions = result.dedup_names(DedupStrategy::Suffix);
+    /// assert_eq!(collisions[0].name, "token");
+    /// assert_eq!(collisions[0].codepoints, vec![1000, 2000]);
+    /// assert_eq!(result.values[&1000].name(), "token");
+    /// assert_eq!(result.values[&2000].name(), "token_2");
+    /// ```
+    pub fn dedup_names(
+        &mut self,
+        strategy: DedupStrategy,
+    ) -> Vec<NameCollision> {
+        let mut names: HashSet<String> = HashSet::new();
+        let mut codepoints_by_name: BTreeMap<String, Vec<u64>> =
+            BTreeMap::new();
+        for value in self.values.values() {
+            if let Some(name) = value.assigned_name() {
+                names.insert(name.to_string());
+                codepoints_by_name
+                    .entry(name.to_string())
+                    .or_default()
+                    .push(value.value());
+            }
+        }
+
+        let mut collisions = Vec::new();
+        for (name, mut codepoints) in codepoints_by_name {
+            if codepoints.len() < 2 {
+                continue;
+            }
+            codepoints.sort_unstable();
+            if strategy == DedupStrategy::Suffix {
+                let mut suffix = 2;
+                for codepoint in &codepoints[1..] {
+                    let renamed = loop {
+                        let candidate = format!("{}_{}", name, suffix);
+                        suffix += 1;
+                        if !names.contains(&candidate) {
+                            break candidate;
+                        }
+                    };
+                    names.insert(renamed.clone());
+                    if let Some(value) = self.values.get_mut(codepoint) {
+                        *value =
+                            value.clone().with_assigned_name(Some(renamed));
+                    }
+                }
+            }
+            collisions.push(NameCollision { name, codepoints });
+        }
+        collisions
+    }
+
+    /// Merges a single value according to `strategy`, recording any
+    /// collision in [`LoadResult::overrides`].
+    fn merge_value(&mut self, value: KnownValue, strategy: MergeStrategy) {
+        let value_override = match strategy {
+            MergeStrategy::LastWins => self
+                .values
+                .insert(value.value(), value.clone())
+                .map(|discarded| ValueOverride { kept: value, discarded }),
+            MergeStrategy::FirstWins => match self.values.get(&value.value()) {
+                Some(kept) => {
+                    Some(ValueOverride { kept: kept.clone(), discarded: value })
+                }
+                None => {
+                    self.values.insert(value.value(), value);
+                    None
+                }
+            },
+        };
+        self.overrides.extend(value_override);
+    }
+
+    /// Returns a machine-readable summary of the load as a JSON object.
+    ///
+    /// The report contains a `counts` object, the `files_processed`, the
+    /// per-file `ontologies`, the `overrides` applied while merging, and the
+    /// `errors` encountered, each with its path and message. Paths are
+    /// rendered with [`Path::display`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, LoadResult};
+    ///
+    /// let mut result = LoadResult::default();
+    /// result.values.insert(
+    ///     1000,
+    ///     KnownValue::new_with_name(1000u64, "myValue".to_string()),
+    /// );
+    ///
+    /// let report = result.to_json();
+    /// assert_eq!(report["counts"]["values"], 1);
+    /// assert_eq!(report["errors"].as_array().unwrap().len(), 0);
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        let ontologies: Vec<_> = self
+            .ontologies
+            .iter()
+            .map(|(file, ontology)| {
+                serde_json::json!({
+                    "file": file.display().to_string(),
+                    "name": ontology.name,
+                    "source_url": ontology.source_url,
+                    "start_code_point": ontology.start_code_point,
+                    "processing_strategy": ontology.processing_strategy,
+                })
+            })
+            .collect();
+        let overrides: Vec<_> = self
+            .overrides
+            .iter()
+            .map(|value_override| {
+                serde_json::json!({
+                    "codepoint": value_override.kept.value(),
+                    "kept": value_override.kept.name(),
+                    "discarded": value_override.discarded.name(),
+                })
+            })
+            .collect();
+        let errors: Vec<_> = self
+            .errors
+            .iter()
+            .map(|(path, error)| {
+                serde_json::json!({
+                    "path": path.display().to_string(),
+                    "message": error.to_string(),
+                })
+            })
+            .collect();
+        let files_processed: Vec<_> = self
+            .files_processed
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+
+        serde_json::json!({
+            "counts": {
+                "values": self.values.len(),
+                "files_processed": self.files_processed.len(),
+                "ontologies": self.ontologies.len(),
+                "overrides": self.overrides.len(),
+                "errors": self.errors.len(),
+                "files_scanned": self.files_scanned,
+                "files_parsed": self.files_parsed,
+                "entries_loaded": self.entries_loaded,
+                "entries_skipped": self.entries_skipped,
+            },
+            "files_processed": files_processed,
+            "ontologies": ontologies,
+            "overrides": overrides,
+            "errors": errors,
+        })
+    }
 }
 
-/// Result type for tolerant directory loading: successfully loaded values and
-/// per-file errors.
-type TolerantLoadResult = (Vec<KnownValue>, Vec<(PathBuf, LoadError)>);
+/// Consumes the result, yielding the loaded known values.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{KnownValue, LoadResult};
+///
+/// let mut result = LoadResult::default();
+/// result.values.insert(
+///     1000,
+///     KnownValue::new_with_name(1000u64, "myValue".to_string()),
+/// );
+///
+/// for known_value in result {
+///     assert_eq!(known_value.name(), "myValue");
+/// }
+/// ```
+impl IntoIterator for LoadResult {
+    type Item = KnownValue;
+    type IntoIter = std::collections::hash_map::IntoValues<u64, KnownValue>;
+
+    fn into_iter(self) -> Self::IntoIter { self.values.into_values() }
+}
+
+/// Result type for tolerant directory loading: successfully loaded values
+/// grouped by file, per-file ontology metadata, per-file errors, and the
+/// number of directory entries scanned.
+type TolerantLoadResult = (
+    Vec<(PathBuf, Vec<KnownValue>)>,
+    Vec<(PathBuf, OntologyInfo)>,
+    Vec<(PathBuf, LoadError)>,
+    usize,
+);
+
+/// How values with the same codepoint from different search paths are
+/// merged.
+///
+/// In configuration files, strategies are written as `"last_wins"` and
+/// `"first_wins"`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Values from later paths override values from earlier paths.
+    #[default]
+    LastWins,
+    /// Values from earlier paths take precedence over later paths.
+    FirstWins,
+}
 
 /// Configuration for loading known values from directories.
 ///
 /// This struct specifies which directories to search for JSON registry files.
-/// Directories are processed in order, with values from later directories
-/// overriding values from earlier directories when codepoints collide.
+/// Directories are processed in order. By default, values from later
+/// directories override values from earlier directories when codepoints
+/// collide; see [`MergeStrategy`].
+///
+/// Configurations compare and hash by their paths and options, so they can
+/// key a cache of load results. Error handlers installed with
+/// [`DirectoryConfig::with_error_handler`] and entry transforms installed
+/// with [`DirectoryConfig::with_entry_transform`] are ignored by both.
 ///
 /// # Examples
 ///
@@ -198,27 +977,134 @@ type TolerantLoadResult = (Vec<KnownValue>, Vec<(PathBuf, LoadError)>);
 ///     "/etc/known-values".into(),
 /// ]);
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DirectoryConfig {
     /// Search paths in priority order (later paths override earlier).
     paths: Vec<PathBuf>,
+    /// Search paths relative to the running executable's directory,
+    /// resolved at load time and scanned before `paths`.
+    exe_relative_paths: Vec<PathBuf>,
+    /// Whether `try_load_from_config` stops at the first error.
+    fail_fast: bool,
+    /// Whether `try_load_from_config` fails on a search path that is not a
+    /// directory.
+    strict: bool,
+    /// How colliding codepoints from different paths are resolved.
+    merge_strategy: MergeStrategy,
+    /// Whether `~` and environment variables are expanded when loading.
+    expand_paths: bool,
+    /// Whether deprecated entries are excluded from the loaded values.
+    skip_deprecated: bool,
+    /// Whether empty or whitespace-only files are skipped silently.
+    skip_empty_files: bool,
+    /// Whether distinct codepoints may share an assigned name.
+    allow_duplicate_names: bool,
+    /// Whether trailing commas are tolerated in registry files.
+    lenient_json: bool,
+    /// Whether symlinked registry files found by scanning are loaded.
+    follow_symlinks: bool,
+    /// The manifest file name that, when present in a directory, lists the
+    /// files to load.
+    manifest: Option<String>,
+    /// Called for each non-fatal error as it is encountered.
+    error_handler: Option<ErrorHandler>,
+    /// Called for each parsed entry before it becomes a KnownValue.
+    entry_transform: Option<EntryTransform>,
+    /// How many non-fatal errors a reload of the global registry tolerates.
+    max_reload_errors: usize,
+}
+
+/// A shared callback invoked for each non-fatal load error.
+#[derive(Clone)]
+struct ErrorHandler(Arc<dyn Fn(&Path, &LoadError) + Send + Sync>);
+
+impl fmt::Debug for ErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ErrorHandler")
+    }
+}
+
+// Closures cannot be compared, so handlers never distinguish one
+// configuration from another.
+impl PartialEq for ErrorHandler {
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+
+impl Eq for ErrorHandler {}
+
+impl std::hash::Hash for ErrorHandler {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+/// A shared callback that rewrites or drops each parsed registry entry.
+#[derive(Clone)]
+struct EntryTransform(
+    Arc<dyn Fn(RegistryEntry) -> Option<RegistryEntry> + Send + Sync>,
+);
+
+impl fmt::Debug for EntryTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EntryTransform")
+    }
+}
+
+// Like error handlers, transforms never distinguish one configuration from
+// another.
+impl PartialEq for EntryTransform {
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+
+impl Eq for EntryTransform {}
+
+impl std::hash::Hash for EntryTransform {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+impl Default for DirectoryConfig {
+    fn default() -> Self {
+        Self {
+            paths: Vec::new(),
+            exe_relative_paths: Vec::new(),
+            fail_fast: false,
+            strict: false,
+            merge_strategy: MergeStrategy::default(),
+            expand_paths: false,
+            skip_deprecated: false,
+            skip_empty_files: true,
+            allow_duplicate_names: true,
+            lenient_json: false,
+            follow_symlinks: true,
+            manifest: None,
+            error_handler: None,
+            entry_transform: None,
+            max_reload_errors: 0,
+        }
+    }
 }
 
 impl DirectoryConfig {
     /// Creates a new empty configuration with no search paths.
-    pub fn new() -> Self { Self { paths: Vec::new() } }
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns a [`DirectoryConfigBuilder`] for setting several options in
+    /// one chain.
+    pub fn builder() -> DirectoryConfigBuilder {
+        DirectoryConfigBuilder::default()
+    }
 
     /// Creates configuration with only the default directory
     /// (`~/.known-values/`).
     pub fn default_only() -> Self {
-        Self { paths: vec![Self::default_directory()] }
+        Self::with_paths(vec![Self::default_directory()])
     }
 
     /// Creates configuration with custom paths (processed in order).
     ///
     /// Later paths in the list take precedence over earlier paths when
     /// values have the same codepoint.
-    pub fn with_paths(paths: Vec<PathBuf>) -> Self { Self { paths } }
+    pub fn with_paths(paths: Vec<PathBuf>) -> Self {
+        Self { paths, ..Self::default() }
+    }
 
     /// Creates configuration with custom paths followed by the default
     /// directory.
@@ -227,7 +1113,145 @@ impl DirectoryConfig {
     /// so its values will override values from the custom paths.
     pub fn with_paths_and_default(mut paths: Vec<PathBuf>) -> Self {
         paths.push(Self::default_directory());
-        Self { paths }
+        Self::with_paths(paths)
+    }
+
+    /// Creates configuration with the XDG base directories for known values.
+    ///
+    /// The search paths are `known-values` under [`dirs::data_dir`]
+    /// (`$XDG_DATA_HOME`, usually `~/.local/share`) followed by
+    /// `known-values` under [`dirs::config_dir`] (`$XDG_CONFIG_HOME`, usually
+    /// `~/.config`), so configuration overrides data under
+    /// [`MergeStrategy::LastWins`]. Directories that cannot be determined on
+    /// the current platform are omitted, and on platforms where both are the
+    /// same directory, such as macOS and Windows, it is searched once. Use
+    /// [`DirectoryConfig::xdg_and_default`] to also search
+    /// `~/.known-values/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::DirectoryConfig;
+    ///
+    /// let config = DirectoryConfig::xdg();
+    /// assert!(
+    ///     config
+    ///         .paths()
+    ///         .iter()
+    ///         .all(|path| path.ends_with("known-values"))
+    /// );
+    /// ```
+    pub fn xdg() -> Self {
+        Self::with_paths(xdg_paths(dirs::data_dir(), dirs::config_dir()))
+    }
+
+    /// Creates configuration with the XDG base directories followed by the
+    /// default directory.
+    ///
+    /// The default directory (`~/.known-values/`) is appended after the
+    /// paths from [`DirectoryConfig::xdg`], so its values override theirs.
+    pub fn xdg_and_default() -> Self {
+        Self::with_paths_and_default(xdg_paths(
+            dirs::data_dir(),
+            dirs::config_dir(),
+        ))
+    }
+
+    /// Creates configuration with a single path relative to the directory
+    /// containing the running executable.
+    ///
+    /// The path is resolved against the parent of
+    /// [`std::env::current_exe`] each time the configuration is loaded, so a
+    /// `known-values/` folder can ship next to a portable binary.
+    /// Exe-relative paths are scanned before any paths added later, which
+    /// therefore take precedence under [`MergeStrategy::LastWins`]. If the
+    /// executable's location cannot be determined, the path is skipped and
+    /// the failure is recorded as a non-fatal error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::DirectoryConfig;
+    ///
+    /// let mut config = DirectoryConfig::with_exe_relative("known-values");
+    /// config.add_path("/etc/known-values".into());
+    /// assert_eq!(config.exe_relative_paths().len(), 1);
+    /// assert_eq!(config.paths().len(), 1);
+    /// ```
+    pub fn with_exe_relative(subpath: &str) -> Self {
+        Self {
+            exe_relative_paths: vec![PathBuf::from(subpath)],
+            ..Self::default()
+        }
+    }
+
+    /// Reads a configuration from a JSON file.
+    ///
+    /// The file is an object whose fields mirror the builder methods; every
+    /// field is optional and defaults to the value used by
+    /// [`DirectoryConfig::new`]. Paths are used as written, so relative paths
+    /// are resolved against the working directory at load time. Unknown
+    /// fields are rejected so that typos do not go unnoticed.
+    ///
+    /// ```json
+    /// {
+    ///   "paths": ["/etc/known-values", "~/.known-values"],
+    ///   "merge_strategy": "first_wins",
+    ///   "fail_fast": true,
+    ///   "strict": true,
+    ///   "expand_paths": true,
+    ///   "skip_deprecated": false,
+    ///   "skip_empty_files": true,
+    ///   "allow_duplicate_names": true,
+    ///   "lenient_json": false,
+    ///   "follow_symlinks": true,
+    ///   "manifest": "index.json",
+    ///   "max_reload_errors": 0
+    /// }
+    /// ```
+    ///
+    /// Returns [`ConfigError::InvalidConfigFile`] if the file cannot be read
+    /// or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let invalid = |message: String| ConfigError::InvalidConfigFile {
+            file: path.to_path_buf(),
+            message,
+        };
+        let content =
+            fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+        let file: DirectoryConfigFile = serde_json::from_str(&content)
+            .map_err(|e| invalid(e.to_string()))?;
+
+        let defaults = Self::default();
+        let config = Self::with_paths(file.paths)
+            .fail_fast(file.fail_fast.unwrap_or(defaults.fail_fast))
+            .strict(file.strict.unwrap_or(defaults.strict))
+            .merge_strategy(
+                file.merge_strategy.unwrap_or(defaults.merge_strategy),
+            )
+            .expand_paths(file.expand_paths.unwrap_or(defaults.expand_paths))
+            .skip_deprecated(
+                file.skip_deprecated.unwrap_or(defaults.skip_deprecated),
+            )
+            .skip_empty_files(
+                file.skip_empty_files.unwrap_or(defaults.skip_empty_files),
+            )
+            .allow_duplicate_names(
+                file.allow_duplicate_names
+                    .unwrap_or(defaults.allow_duplicate_names),
+            )
+            .lenient_json(file.lenient_json.unwrap_or(defaults.lenient_json))
+            .follow_symlinks(
+                file.follow_symlinks.unwrap_or(defaults.follow_symlinks),
+            )
+            .max_reload_errors(
+                file.max_reload_errors.unwrap_or(defaults.max_reload_errors),
+            );
+        Ok(match file.manifest {
+            Some(manifest) => config.use_manifest(manifest),
+            None => config,
+        })
     }
 
     /// Returns the default directory: `~/.known-values/`
@@ -243,17 +1267,549 @@ impl DirectoryConfig {
     /// Returns the configured search paths.
     pub fn paths(&self) -> &[PathBuf] { &self.paths }
 
+    /// Returns the configured paths relative to the executable's directory,
+    /// as given to [`DirectoryConfig::with_exe_relative`].
+    pub fn exe_relative_paths(&self) -> &[PathBuf] { &self.exe_relative_paths }
+
     /// Adds a path to the configuration.
     ///
     /// The new path will be processed after existing paths, so its values
     /// will override values from earlier paths.
     pub fn add_path(&mut self, path: PathBuf) { self.paths.push(path); }
+
+    /// Inserts a path at the front of the configuration.
+    ///
+    /// The new path will be processed before existing paths. Under
+    /// [`MergeStrategy::LastWins`] (the default), its values are overridden
+    /// by every other path; under [`MergeStrategy::FirstWins`], its values
+    /// take precedence over everything else.
+    pub fn prepend_path(&mut self, path: PathBuf) {
+        self.paths.insert(0, path);
+    }
+
+    /// Removes all paths, including exe-relative paths, from the
+    /// configuration.
+    pub fn clear_paths(&mut self) {
+        self.paths.clear();
+        self.exe_relative_paths.clear();
+    }
+
+    /// Replaces the configured paths with `paths`, processed in the given
+    /// order.
+    pub fn set_paths(&mut self, paths: Vec<PathBuf>) { self.paths = paths; }
+
+    /// Sets whether loading should stop at the first error.
+    ///
+    /// When enabled, [`try_load_from_config`] returns the first error it
+    /// encounters instead of collecting it into [`LoadResult::errors`]. This
+    /// is useful in CI, where any malformed registry file should fail the
+    /// build. [`load_from_config`] is always fault-tolerant and ignores this
+    /// setting.
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Returns true if loading should stop at the first error.
+    pub fn is_fail_fast(&self) -> bool { self.fail_fast }
+
+    /// Sets whether a search path that exists but is not a directory is a
+    /// fatal error.
+    ///
+    /// By default such a path is recorded as a non-fatal
+    /// [`LoadError::NotADirectory`] in [`LoadResult::errors`] and loading
+    /// continues. When enabled, [`try_load_from_config`] returns that error
+    /// instead, even without [`DirectoryConfig::fail_fast`].
+    /// [`load_from_config`] always records it.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns true if a search path that is not a directory is fatal.
+    pub fn is_strict(&self) -> bool { self.strict }
+
+    /// Sets how values with the same codepoint from different paths are
+    /// merged.
+    pub fn merge_strategy(mut self, merge_strategy: MergeStrategy) -> Self {
+        self.merge_strategy = merge_strategy;
+        self
+    }
+
+    /// Returns the merge strategy for colliding codepoints.
+    pub fn get_merge_strategy(&self) -> MergeStrategy { self.merge_strategy }
+
+    /// Sets whether search paths are expanded when loading.
+    ///
+    /// When enabled, a leading `~` is replaced with the home directory, and
+    /// `$VAR` and `${VAR}` are replaced with the value of the environment
+    /// variable `VAR`. References to unset variables are left as literal text,
+    /// so the path will usually not exist and will be skipped like any other
+    /// missing directory. Expansion is disabled by default.
+    pub fn expand_paths(mut self, expand_paths: bool) -> Self {
+        self.expand_paths = expand_paths;
+        self
+    }
+
+    /// Returns true if search paths are expanded when loading.
+    pub fn is_expand_paths(&self) -> bool { self.expand_paths }
+
+    /// Sets whether entries marked `"deprecated": true` are excluded from the
+    /// loaded values.
+    ///
+    /// Deprecated entries are still parsed, so malformed files are reported
+    /// either way. Deprecated entries are included by default.
+    pub fn skip_deprecated(mut self, skip_deprecated: bool) -> Self {
+        self.skip_deprecated = skip_deprecated;
+        self
+    }
+
+    /// Returns true if deprecated entries are excluded when loading.
+    pub fn is_skip_deprecated(&self) -> bool { self.skip_deprecated }
+
+    /// Sets whether empty or whitespace-only registry files are skipped.
+    ///
+    /// Tools often create a file before writing its contents, so empty files
+    /// are skipped silently by default. When disabled, they are reported as
+    /// [`LoadError::EmptyFile`].
+    pub fn skip_empty_files(mut self, skip_empty_files: bool) -> Self {
+        self.skip_empty_files = skip_empty_files;
+        self
+    }
+
+    /// Returns true if empty registry files are skipped silently.
+    pub fn is_skip_empty_files(&self) -> bool { self.skip_empty_files }
+
+    /// Sets whether distinct codepoints may be loaded with the same assigned
+    /// name.
+    ///
+    /// A store assigns each name to one codepoint, so when two codepoints
+    /// share a name, only the last one inserted keeps it. Duplicates are
+    /// allowed by default. When disallowed, the later value is skipped and
+    /// reported as [`LoadError::DuplicateName`].
+    pub fn allow_duplicate_names(
+        mut self,
+        allow_duplicate_names: bool,
+    ) -> Self {
+        self.allow_duplicate_names = allow_duplicate_names;
+        self
+    }
+
+    /// Returns true if distinct codepoints may share an assigned name.
+    pub fn is_allow_duplicate_names(&self) -> bool {
+        self.allow_duplicate_names
+    }
+
+    /// Sets whether registry files may contain trailing commas.
+    ///
+    /// Hand-edited files often end up with a comma after the last element of
+    /// an array or object, which strict JSON rejects. When enabled, such
+    /// commas are ignored. Registry files are parsed strictly by default.
+    pub fn lenient_json(mut self, lenient_json: bool) -> Self {
+        self.lenient_json = lenient_json;
+        self
+    }
+
+    /// Returns true if trailing commas are tolerated in registry files.
+    pub fn is_lenient_json(&self) -> bool { self.lenient_json }
+
+    /// Sets whether symbolic links to registry files are loaded when
+    /// scanning a directory.
+    ///
+    /// Symlinked files are followed by default. When disabled, directory
+    /// entries that are symlinks are skipped, so only regular files stored
+    /// in the directory itself are loaded. Files listed in a manifest are
+    /// always loaded as listed. Subdirectories are never descended into, so
+    /// symlinked directories cannot create cycles.
+    pub fn follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Returns true if symlinked registry files are loaded when scanning.
+    pub fn is_follow_symlinks(&self) -> bool { self.follow_symlinks }
+
+    /// Sets the name of a manifest file that controls which files are loaded
+    /// from each directory.
+    ///
+    /// When a directory contains the manifest, only the files it lists are
+    /// loaded, in the listed order; other files in the directory are
+    /// ignored. Directories without the manifest are scanned for `*.json`
+    /// files as usual. The manifest lists paths relative to its directory:
+    ///
+    /// ```json
+    /// { "files": ["base.json", "overrides.json"] }
+    /// ```
+    ///
+    /// Absolute paths and paths with `..` components are rejected with
+    /// [`LoadError::InvalidManifestEntry`], and nothing is loaded from that
+    /// directory. A listed file that does not exist is reported like any
+    /// other unreadable file.
+    pub fn use_manifest(mut self, filename: impl Into<String>) -> Self {
+        self.manifest = Some(filename.into());
+        self
+    }
+
+    /// Returns the manifest file name, if one is configured.
+    pub fn manifest(&self) -> Option<&str> { self.manifest.as_deref() }
+
+    /// Sets a handler that is called for each non-fatal error as it is
+    /// encountered during loading, e.g. to log it or record a metric.
+    ///
+    /// The handler is called with the path the error is reported against,
+    /// just before the error is added to [`LoadResult::errors`]. Loading
+    /// continues once the handler returns; use
+    /// [`DirectoryConfig::fail_fast`] to stop at the first error instead.
+    /// Errors returned by [`try_load_from_config`] in fail-fast mode are not
+    /// passed to the handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::DirectoryConfig;
+    ///
+    /// let config =
+    ///     DirectoryConfig::new().with_error_handler(Box::new(|path, error| {
+    ///         eprintln!("{}: {}", path.display(), error)
+    ///     }));
+    /// assert!(config.has_error_handler());
+    /// ```
+    pub fn with_error_handler(
+        mut self,
+        handler: Box<dyn Fn(&Path, &LoadError) + Send + Sync>,
+    ) -> Self {
+        self.error_handler = Some(ErrorHandler(Arc::from(handler)));
+        self
+    }
+
+    /// Returns true if an error handler is configured.
+    pub fn has_error_handler(&self) -> bool { self.error_handler.is_some() }
+
+    /// Sets a transform that is applied to each parsed registry entry before
+    /// it becomes a [`KnownValue`].
+    ///
+    /// The transform may rewrite the entry, e.g. to shift codepoints into a
+    /// namespaced block or normalize names, or return `None` to drop it.
+    /// Dropped entries are not counted in [`LoadResult::entries_skipped`].
+    /// Options such as [`DirectoryConfig::skip_deprecated`] and duplicate
+    /// name detection apply to the transformed entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::DirectoryConfig;
+    ///
+    /// let config =
+    ///     DirectoryConfig::new().with_entry_transform(Box::new(|mut entry| {
+    ///         entry.codepoint = entry.codepoint.checked_add(100_000)?;
+    ///         Some(entry)
+    ///     }));
+    /// assert!(config.has_entry_transform());
+    /// ```
+    pub fn with_entry_transform(
+        mut self,
+        transform: Box<
+            dyn Fn(RegistryEntry) -> Option<RegistryEntry> + Send + Sync,
+        >,
+    ) -> Self {
+        self.entry_transform = Some(EntryTransform(Arc::from(transform)));
+        self
+    }
+
+    /// Returns true if an entry transform is configured.
+    pub fn has_entry_transform(&self) -> bool { self.entry_transform.is_some() }
+
+    /// Applies the configured entry transform, if any, to `entry`.
+    fn transform_entry(&self, entry: RegistryEntry) -> Option<RegistryEntry> {
+        match &self.entry_transform {
+            Some(EntryTransform(transform)) => transform(entry),
+            None => Some(entry),
+        }
+    }
+
+    /// Sets how many non-fatal errors [`reload_known_values`] tolerates
+    /// before rejecting the reload and keeping the previous registry.
+    ///
+    /// Defaults to 0, so any error during a reload keeps the previous
+    /// registry. The initial load of the global registry is not affected.
+    pub fn max_reload_errors(mut self, max_reload_errors: usize) -> Self {
+        self.max_reload_errors = max_reload_errors;
+        self
+    }
+
+    /// Returns how many non-fatal errors a reload tolerates.
+    pub fn get_max_reload_errors(&self) -> usize { self.max_reload_errors }
+}
+
+/// A fluent builder for [`DirectoryConfig`].
+///
+/// Every option of [`DirectoryConfig`] has a chained setter; options that are
+/// not set keep the defaults of [`DirectoryConfig::new`].
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{DirectoryConfig, MergeStrategy};
+///
+/// let config = DirectoryConfig::builder()
+///     .path("/etc/known-values")
+///     .default_path()
+///     .merge_strategy(MergeStrategy::FirstWins)
+///     .fail_fast(true)
+///     .expand_paths(true)
+///     .skip_deprecated(true)
+///     .allow_duplicate_names(false)
+///     .build();
+///
+/// assert_eq!(config.paths().len(), 2);
+/// assert_eq!(config.paths()[1], DirectoryConfig::default_directory());
+/// assert_eq!(config.get_merge_strategy(), MergeStrategy::FirstWins);
+/// assert!(config.is_fail_fast());
+/// assert!(config.is_expand_paths());
+/// assert!(config.is_skip_deprecated());
+/// assert!(config.is_skip_empty_files());
+/// assert!(!config.is_allow_duplicate_names());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryConfigBuilder {
+    config: DirectoryConfig,
+}
+
+impl DirectoryConfigBuilder {
+    /// Appends a search path.
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.add_path(path.into());
+        self
+    }
+
+    /// Appends several search paths, in order.
+    pub fn paths<I>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<PathBuf>,
+    {
+        self.config.paths.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Appends the default directory (`~/.known-values/`).
+    pub fn default_path(self) -> Self {
+        self.path(DirectoryConfig::default_directory())
+    }
+
+    /// Appends a path relative to the executable's directory.
+    ///
+    /// See [`DirectoryConfig::with_exe_relative`].
+    pub fn exe_relative_path(mut self, subpath: impl Into<PathBuf>) -> Self {
+        self.config.exe_relative_paths.push(subpath.into());
+        self
+    }
+
+    /// See [`DirectoryConfig::fail_fast`].
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.config = self.config.fail_fast(fail_fast);
+        self
+    }
+
+    /// See [`DirectoryConfig::strict`].
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.config = self.config.strict(strict);
+        self
+    }
+
+    /// See [`DirectoryConfig::merge_strategy`].
+    pub fn merge_strategy(mut self, merge_strategy: MergeStrategy) -> Self {
+        self.config = self.config.merge_strategy(merge_strategy);
+        self
+    }
+
+    /// See [`DirectoryConfig::expand_paths`].
+    pub fn expand_paths(mut self, expand_paths: bool) -> Self {
+        self.config = self.config.expand_paths(expand_paths);
+        self
+    }
+
+    /// See [`DirectoryConfig::skip_deprecated`].
+    pub fn skip_deprecated(mut self, skip_deprecated: bool) -> Self {
+        self.config = self.config.skip_deprecated(skip_deprecated);
+        self
+    }
+
+    /// See [`DirectoryConfig::skip_empty_files`].
+    pub fn skip_empty_files(mut self, skip_empty_files: bool) -> Self {
+        self.config = self.config.skip_empty_files(skip_empty_files);
+        self
+    }
+
+    /// See [`DirectoryConfig::allow_duplicate_names`].
+    pub fn allow_duplicate_names(
+        mut self,
+        allow_duplicate_names: bool,
+    ) -> Self {
+        self.config = self.config.allow_duplicate_names(allow_duplicate_names);
+        self
+    }
+
+    /// See [`DirectoryConfig::lenient_json`].
+    pub fn lenient_json(mut self, lenient_json: bool) -> Self {
+        self.config = self.config.lenient_json(lenient_json);
+        self
+    }
+
+    /// See [`DirectoryConfig::follow_symlinks`].
+    pub fn follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.config = self.config.follow_symlinks(follow_symlinks);
+        self
+    }
+
+    /// See [`DirectoryConfig::use_manifest`].
+    pub fn use_manifest(mut self, filename: impl Into<String>) -> Self {
+        self.config = self.config.use_manifest(filename);
+        self
+    }
+
+    /// See [`DirectoryConfig::max_reload_errors`].
+    pub fn max_reload_errors(mut self, max_reload_errors: usize) -> Self {
+        self.config = self.config.max_reload_errors(max_reload_errors);
+        self
+    }
+
+    /// See [`DirectoryConfig::with_error_handler`].
+    pub fn with_error_handler(
+        mut self,
+        handler: Box<dyn Fn(&Path, &LoadError) + Send + Sync>,
+    ) -> Self {
+        self.config = self.config.with_error_handler(handler);
+        self
+    }
+
+    /// See [`DirectoryConfig::with_entry_transform`].
+    pub fn with_entry_transform(
+        mut self,
+        transform: Box<
+            dyn Fn(RegistryEntry) -> Option<RegistryEntry> + Send + Sync,
+        >,
+    ) -> Self {
+        self.config = self.config.with_entry_transform(transform);
+        self
+    }
+
+    /// Returns the configured [`DirectoryConfig`].
+    pub fn build(self) -> DirectoryConfig { self.config }
+}
+
+/// On-disk representation of a [`DirectoryConfig`], read by
+/// [`DirectoryConfig::from_file`].
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DirectoryConfigFile {
+    #[serde(default)]
+    paths: Vec<PathBuf>,
+    fail_fast: Option<bool>,
+    strict: Option<bool>,
+    merge_strategy: Option<MergeStrategy>,
+    expand_paths: Option<bool>,
+    skip_deprecated: Option<bool>,
+    skip_empty_files: Option<bool>,
+    allow_duplicate_names: Option<bool>,
+    lenient_json: Option<bool>,
+    follow_symlinks: Option<bool>,
+    manifest: Option<String>,
+    max_reload_errors: Option<usize>,
+}
+
+/// A directory manifest, listing registry files in load order.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DirectoryManifest {
+    files: Vec<PathBuf>,
+}
+
+/// Returns the `known-values` directories under the given XDG data and
+/// config directories, in that order, skipping any that are unknown.
+///
+/// The config directory is skipped if it is the data directory, as on macOS
+/// and Windows, so that its files are not loaded twice.
+fn xdg_paths(
+    data_dir: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
+) -> Vec<PathBuf> {
+    let config_dir = config_dir.filter(|dir| Some(dir) != data_dir.as_ref());
+    [data_dir, config_dir]
+        .into_iter()
+        .flatten()
+        .map(|dir| dir.join("known-values"))
+        .collect()
+}
+
+/// Expands a leading `~` and `$VAR`/`${VAR}` references in a path.
+///
+/// Unset variables and paths that are not valid UTF-8 are left unchanged.
+fn expand_path(path: &Path) -> PathBuf {
+    expand_path_with(path, dirs::home_dir(), |name| std::env::var(name).ok())
+}
+
+/// Expands `path` like [`expand_path`], using `home` for `~` and `lookup` to
+/// resolve variables.
+fn expand_path_with(
+    path: &Path,
+    home: Option<PathBuf>,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> PathBuf {
+    let Some(path) = path.to_str() else {
+        return path.to_path_buf();
+    };
+    let path = expand_env_vars(path, lookup);
+    match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => match home {
+            Some(home) => home.join(rest.trim_start_matches('/')),
+            None => PathBuf::from(path),
+        },
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Replaces `$VAR` and `${VAR}` references with the values `lookup` returns
+/// for them.
+fn expand_env_vars(
+    input: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(index) = rest.find('$') {
+        output.push_str(&rest[..index]);
+        let after = &rest[index + 1..];
+        let (name, reference_len) = if let Some(braced) =
+            after.strip_prefix('{')
+        {
+            match braced.find('}') {
+                Some(end) => (&braced[..end], end + 3),
+                None => ("", 1),
+            }
+        } else {
+            let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+            (&after[..end], end + 1)
+        };
+        let reference = &rest[index..index + reference_len];
+        match lookup(name) {
+            Some(value) if !name.is_empty() => output.push_str(&value),
+            _ => output.push_str(reference),
+        }
+        rest = &rest[index + reference_len..];
+    }
+
+    output.push_str(rest);
+    output
 }
 
 /// Loads all JSON registry files from a single directory.
 ///
 /// This function scans the specified directory for files with a `.json`
-/// extension and attempts to parse them as known value registries.
+/// extension and attempts to parse them as known value registries. Empty or
+/// whitespace-only files are skipped.
 ///
 /// # Arguments
 ///
@@ -262,8 +1818,11 @@ impl DirectoryConfig {
 /// # Returns
 ///
 /// Returns `Ok` with a vector of loaded `KnownValue` instances, or an empty
-/// vector if the directory doesn't exist. Returns `Err` only for I/O errors
-/// that prevent directory traversal.
+/// vector if the directory doesn't exist or `path` is not a directory.
+/// Returns `Err` for I/O errors that prevent directory traversal, and
+/// [`LoadError::PermissionDenied`] if the directory or one of its files is
+/// unreadable. Use [`load_from_config`] to have a path that is not a
+/// directory reported as [`LoadError::NotADirectory`].
 ///
 /// # Examples
 ///
@@ -284,40 +1843,114 @@ pub fn load_from_directory(path: &Path) -> Result<Vec<KnownValue>, LoadError> {
         return Ok(values);
     }
 
-    for entry in fs::read_dir(path)? {
+    for entry in fs::read_dir(path).map_err(|e| LoadError::from_io(path, e))? {
         let entry = entry?;
         let file_path = entry.path();
 
         // Only process .json files
         if file_path.extension().is_some_and(|ext| ext == "json") {
-            let content = fs::read_to_string(&file_path)?;
-            let registry: RegistryFile =
-                serde_json::from_str(&content).map_err(|e| {
-                    LoadError::Json { file: file_path.clone(), error: e }
-                })?;
+            let registry = load_single_file(&file_path, true, false)?;
+            values.extend(registry.entries.into_iter().map(KnownValue::from));
+        }
+    }
 
-            for entry in registry.entries {
-                values.push(KnownValue::new_with_name(
-                    entry.codepoint,
-                    entry.name,
-                ));
+    Ok(values)
+}
+
+/// Loads known values from the `.json` members of a zip archive.
+///
+/// Members are loaded in archive order, each as if it were a registry file
+/// in a directory, and values from later members override earlier ones when
+/// codepoints collide. Ontology metadata, overrides, and per-member errors
+/// are reported against the member's archive-relative name; errors opening
+/// the archive itself are reported against `path`. Empty members are skipped.
+///
+/// This function is only available when the `archive` feature is enabled.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use known_values::load_from_archive;
+/// use std::path::Path;
+///
+/// let result = load_from_archive(Path::new("registries.zip"));
+/// println!("Loaded {} values", result.values_count());
+/// ```
+#[cfg(feature = "archive")]
+pub fn load_from_archive(path: &Path) -> LoadResult {
+    use std::io::Read;
+
+    let zip_error =
+        |e: zip::result::ZipError| LoadError::Io(io::Error::other(e));
+    let mut result = LoadResult::default();
+    let archive = fs::File::open(path)
+        .map_err(LoadError::from)
+        .and_then(|file| zip::ZipArchive::new(file).map_err(zip_error));
+    let mut archive = match archive {
+        Ok(archive) => archive,
+        Err(e) => {
+            result.errors.push((path.to_path_buf(), e));
+            return result;
+        }
+    };
+
+    for index in 0..archive.len() {
+        let mut member = match archive.by_index(index) {
+            Ok(member) => member,
+            Err(e) => {
+                result.errors.push((path.to_path_buf(), zip_error(e)));
+                continue;
+            }
+        };
+        if !member.is_file() {
+            continue;
+        }
+        result.files_scanned += 1;
+        if !member.name().ends_with(".json") {
+            continue;
+        }
+        let member_path = PathBuf::from(member.name());
+        let mut content = String::new();
+        let registry = member
+            .read_to_string(&mut content)
+            .map_err(|e| LoadError::from_io(&member_path, e))
+            .and_then(|_| {
+                parse_registry_content(&content, &member_path, true, false)
+            });
+        match registry {
+            Ok(registry) => {
+                result.files_parsed += 1;
+                for entry in registry.entries {
+                    result.merge_value(entry.into(), MergeStrategy::LastWins);
+                    result.entries_loaded += 1;
+                }
+                if let Some(ontology) = registry.ontology {
+                    result.ontologies.push((member_path, ontology));
+                }
             }
+            Err(e) => result.errors.push((member_path, e)),
         }
     }
 
-    Ok(values)
+    result.files_processed.push(path.to_path_buf());
+    result
 }
 
 /// Loads known values from all directories in the given configuration.
 ///
 /// Directories are processed in order. When multiple entries have the same
 /// codepoint, values from later directories override values from earlier
-/// directories.
+/// directories, unless the configuration uses [`MergeStrategy::FirstWins`].
 ///
 /// This function is fault-tolerant: it will continue processing even if
 /// some files fail to parse. Errors are collected in the returned
 /// `LoadResult`.
 ///
+/// A search path that exists but is a regular file is recorded as a
+/// non-fatal [`LoadError::NotADirectory`] in the result's errors rather than
+/// being silently ignored, and unreadable directories or files are recorded as
+/// [`LoadError::PermissionDenied`].
+///
 /// # Arguments
 ///
 /// * `config` - The directory configuration specifying search paths.
@@ -346,76 +1979,525 @@ pub fn load_from_directory(path: &Path) -> Result<Vec<KnownValue>, LoadError> {
 /// }
 /// ```
 pub fn load_from_config(config: &DirectoryConfig) -> LoadResult {
+    load_from_config_impl(config, false, false)
+        .expect("tolerant loading never returns an error")
+}
+
+/// Loads known values from all directories in the given configuration,
+/// honoring [`DirectoryConfig::fail_fast`] and [`DirectoryConfig::strict`].
+///
+/// When fail-fast is enabled, this returns the first error encountered,
+/// whether it is a directory traversal failure, a search path that is a file
+/// rather than a directory, or a file that fails to parse. When only strict
+/// mode is enabled, a search path that is not a directory is returned as an
+/// error and everything else is collected. Otherwise it behaves like
+/// [`load_from_config`] and always returns `Ok`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use known_values::{DirectoryConfig, try_load_from_config};
+///
+/// let config = DirectoryConfig::default_only().fail_fast(true);
+/// let result = try_load_from_config(&config)?;
+/// println!("Loaded {} values", result.values_count());
+/// ```
+pub fn try_load_from_config(
+    config: &DirectoryConfig,
+) -> Result<LoadResult, LoadError> {
+    load_from_config_impl(config, config.is_fail_fast(), config.is_strict())
+}
+
+fn load_from_config_impl(
+    config: &DirectoryConfig,
+    fail_fast: bool,
+    strict: bool,
+) -> Result<LoadResult, LoadError> {
+    load_from_config_with_exe_dir(config, fail_fast, strict, exe_directory)
+}
+
+/// Returns the directory containing the running executable.
+fn exe_directory() -> io::Result<PathBuf> {
+    let exe = std::env::current_exe()?;
+    exe.parent().map(Path::to_path_buf).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "executable has no parent directory",
+        )
+    })
+}
+
+/// Loads from `config`, resolving exe-relative paths against the directory
+/// returned by `exe_dir`.
+fn load_from_config_with_exe_dir(
+    config: &DirectoryConfig,
+    fail_fast: bool,
+    strict: bool,
+    exe_dir: impl Fn() -> io::Result<PathBuf>,
+) -> Result<LoadResult, LoadError> {
     let mut result = LoadResult::default();
+    let mut exe_relative_paths = Vec::new();
+    if !config.exe_relative_paths().is_empty() {
+        match exe_dir() {
+            Ok(dir) => exe_relative_paths.extend(
+                config
+                    .exe_relative_paths()
+                    .iter()
+                    .map(|path| dir.join(path)),
+            ),
+            Err(e) if fail_fast => return Err(LoadError::Io(e)),
+            Err(e) => {
+                for path in config.exe_relative_paths() {
+                    let error = io::Error::new(e.kind(), e.to_string());
+                    record_error(
+                        config,
+                        &mut result,
+                        path.clone(),
+                        LoadError::Io(error),
+                    );
+                }
+            }
+        }
+    }
+    // The codepoint each loaded name was last assigned to, for detecting
+    // duplicate names. Entries may be stale after an override, so they are
+    // checked against `result.values` before use.
+    let mut codepoints_by_name: HashMap<String, u64> = HashMap::new();
 
-    for dir_path in config.paths() {
-        match load_from_directory_tolerant(dir_path) {
-            Ok((values, errors)) => {
-                for value in values {
-                    result.values.insert(value.value(), value);
+    for dir_path in exe_relative_paths.iter().chain(config.paths()) {
+        let expanded;
+        let dir_path = if config.is_expand_paths() {
+            expanded = expand_path(dir_path);
+            &expanded
+        } else {
+            dir_path
+        };
+        match load_from_directory_tolerant(dir_path, config, fail_fast) {
+            Ok((values, ontologies, errors, files_scanned)) => {
+                result.files_scanned += files_scanned;
+                result.files_parsed += values.len();
+                for (file_path, values) in values {
+                    for value in values {
+                        if config.is_skip_deprecated() && value.is_deprecated()
+                        {
+                            result.entries_skipped += 1;
+                            continue;
+                        }
+                        if !config.is_allow_duplicate_names()
+                            && let Some(error) = duplicate_name(
+                                &value,
+                                &result,
+                                &codepoints_by_name,
+                            )
+                        {
+                            if fail_fast {
+                                return Err(error);
+                            }
+                            record_error(
+                                config,
+                                &mut result,
+                                file_path.clone(),
+                                error,
+                            );
+                            result.entries_skipped += 1;
+                            continue;
+                        }
+                        let codepoint = value.value();
+                        result.merge_value(value, config.get_merge_strategy());
+                        result.entries_loaded += 1;
+                        if let Some(name) =
+                            result.values[&codepoint].assigned_name()
+                        {
+                            codepoints_by_name
+                                .insert(name.to_string(), codepoint);
+                        }
+                    }
                 }
-                if !errors.is_empty() {
-                    result.errors.extend(errors);
+                result.ontologies.extend(ontologies);
+                for (file_path, error) in errors {
+                    record_error(config, &mut result, file_path, error);
                 }
                 result.files_processed.push(dir_path.clone());
             }
+            Err(e)
+                if fail_fast
+                    || (strict
+                        && matches!(e, LoadError::NotADirectory { .. })) =>
+            {
+                return Err(e);
+            }
             Err(e) => {
-                result.errors.push((dir_path.clone(), e));
+                record_error(config, &mut result, dir_path.clone(), e);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Records a non-fatal error in `result`, first passing it to the configured
+/// error handler, if any.
+fn record_error(
+    config: &DirectoryConfig,
+    result: &mut LoadResult,
+    path: PathBuf,
+    error: LoadError,
+) {
+    if let Some(ErrorHandler(handler)) = &config.error_handler {
+        handler(&path, &error);
+    }
+    result.errors.push((path, error));
+}
+
+/// Returns a [`LoadError::DuplicateName`] if `value`'s assigned name is
+/// already held by a different codepoint in `result`.
+fn duplicate_name(
+    value: &KnownValue,
+    result: &LoadResult,
+    codepoints_by_name: &HashMap<String, u64>,
+) -> Option<LoadError> {
+    let name = value.assigned_name()?;
+    let codepoint_a = *codepoints_by_name.get(name)?;
+    let holder = result.values.get(&codepoint_a)?;
+    (codepoint_a != value.value() && holder.assigned_name() == Some(name)).then(
+        || LoadError::DuplicateName {
+            name: name.to_string(),
+            codepoint_a,
+            codepoint_b: value.value(),
+        },
+    )
+}
+
+/// Loads from a directory with tolerance for individual file failures.
+///
+/// If `fail_fast` is set, the first file failure is returned as an error
+/// instead.
+fn load_from_directory_tolerant(
+    path: &Path,
+    config: &DirectoryConfig,
+    fail_fast: bool,
+) -> Result<TolerantLoadResult, LoadError> {
+    let mut values = Vec::new();
+    let mut ontologies = Vec::new();
+    let mut errors = Vec::new();
+
+    if !path.exists() {
+        return Ok((values, ontologies, errors, 0));
+    }
+    if !path.is_dir() {
+        return Err(LoadError::NotADirectory { path: path.to_path_buf() });
+    }
+
+    let (file_paths, files_scanned) = registry_file_paths(path, config)?;
+    for file_path in file_paths {
+        match load_single_file(
+            &file_path,
+            config.is_skip_empty_files(),
+            config.is_lenient_json(),
+        ) {
+            Ok(registry) => {
+                if let Some(ontology) = registry.ontology {
+                    ontologies.push((file_path.clone(), ontology));
+                }
+                values.push((
+                    file_path,
+                    registry
+                        .entries
+                        .into_iter()
+                        .filter_map(|entry| config.transform_entry(entry))
+                        .map(KnownValue::from)
+                        .collect(),
+                ));
             }
+            Err(e) if fail_fast => return Err(e),
+            Err(e) => errors.push((file_path, e)),
+        }
+    }
+
+    Ok((values, ontologies, errors, files_scanned))
+}
+
+/// Returns the registry files to load from a directory, in load order, along
+/// with the number of directory entries scanned to find them.
+///
+/// If the configured manifest is present in the directory, the files it
+/// lists are returned, resolved against the directory; an entry that could
+/// resolve outside the directory is reported as
+/// [`LoadError::InvalidManifestEntry`]. Otherwise, every
+/// `*.json` file in the directory is returned, leaving out symlinks unless
+/// [`DirectoryConfig::follow_symlinks`] is set.
+fn registry_file_paths(
+    path: &Path,
+    config: &DirectoryConfig,
+) -> Result<(Vec<PathBuf>, usize), LoadError> {
+    if let Some(manifest) = config.manifest() {
+        let manifest_path = path.join(manifest);
+        if manifest_path.is_file() {
+            let content = fs::read_to_string(&manifest_path)
+                .map_err(|e| LoadError::from_io(&manifest_path, e))?;
+            #[cfg(feature = "jsonc")]
+            let content = strip_json_comments(&content);
+            let manifest: DirectoryManifest =
+                serde_json::from_str(&content).map_err(|error| {
+                    LoadError::Json { file: manifest_path, error }
+                })?;
+            let mut file_paths = Vec::with_capacity(manifest.files.len());
+            for entry in manifest.files {
+                let escapes = entry.components().any(|component| {
+                    !matches!(
+                        component,
+                        Component::Normal(_) | Component::CurDir
+                    )
+                });
+                if escapes {
+                    return Err(LoadError::InvalidManifestEntry {
+                        manifest: manifest_path,
+                        entry,
+                    });
+                }
+                file_paths.push(path.join(entry));
+            }
+            let files_scanned = file_paths.len();
+            return Ok((file_paths, files_scanned));
+        }
+    }
+
+    let mut file_paths = Vec::new();
+    let mut files_scanned = 0;
+    for entry in fs::read_dir(path).map_err(|e| LoadError::from_io(path, e))? {
+        let entry = entry?;
+        files_scanned += 1;
+        if !config.is_follow_symlinks() && entry.file_type()?.is_symlink() {
+            continue;
+        }
+        let file_path = entry.path();
+        if file_path.extension().is_some_and(|ext| ext == "json") {
+            file_paths.push(file_path);
         }
     }
+    Ok((file_paths, files_scanned))
+}
 
-    result
+/// Loads a single JSON registry file.
+///
+/// Empty or whitespace-only files yield an empty registry if `skip_empty` is
+/// set, and a [`LoadError::EmptyFile`] otherwise. Trailing commas are
+/// tolerated if `lenient` is set.
+fn load_single_file(
+    path: &Path,
+    skip_empty: bool,
+    lenient: bool,
+) -> Result<RegistryFile, LoadError> {
+    let content =
+        fs::read_to_string(path).map_err(|e| LoadError::from_io(path, e))?;
+    parse_registry_content(&content, path, skip_empty, lenient)
 }
 
-/// Loads from a directory with tolerance for individual file failures.
-fn load_from_directory_tolerant(
+/// Parses the content of a single registry file, handling empty content like
+/// [`load_single_file`].
+///
+/// A leading UTF-8 byte order mark, as written by some Windows editors, is
+/// ignored.
+fn parse_registry_content(
+    content: &str,
     path: &Path,
-) -> Result<TolerantLoadResult, LoadError> {
-    let mut values = Vec::new();
-    let mut errors = Vec::new();
+    skip_empty: bool,
+    lenient: bool,
+) -> Result<RegistryFile, LoadError> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    if content.trim().is_empty() {
+        return if skip_empty {
+            Ok(RegistryFile::default())
+        } else {
+            Err(LoadError::EmptyFile { file: path.to_path_buf() })
+        };
+    }
+    parse_registry_file(content, path, lenient)
+}
 
-    if !path.exists() || !path.is_dir() {
-        return Ok((values, errors));
+/// Parses a registry file.
+///
+/// Both the enveloped `{"entries": [...]}` form and a bare top-level array of
+/// entries are accepted. If neither parses, the error for the form the
+/// content appears to be using is reported.
+///
+/// When the `jsonc` feature is enabled, `//` line comments and `/* */` block
+/// comments are stripped before parsing. When `lenient` is set, trailing
+/// commas are stripped as well. When the `jsonschema` feature is enabled,
+/// the content is validated against the bundled registry schema.
+fn parse_registry_file(
+    content: &str,
+    path: &Path,
+    lenient: bool,
+) -> Result<RegistryFile, LoadError> {
+    #[cfg(feature = "jsonc")]
+    let content = &strip_json_comments(content);
+    let relaxed;
+    let content = if lenient {
+        relaxed = strip_trailing_commas(content);
+        relaxed.as_str()
+    } else {
+        content
+    };
+    #[cfg(feature = "jsonschema")]
+    validate_registry_schema(content, path)?;
+    let error = match serde_json::from_str::<RegistryFile>(content) {
+        Ok(registry) => return Ok(registry),
+        Err(error) => error,
+    };
+    match serde_json::from_str::<Vec<RegistryEntry>>(content) {
+        Ok(entries) => Ok(RegistryFile { entries, ..Default::default() }),
+        Err(array_error) if content.trim_start().starts_with('[') => {
+            Err(LoadError::Json {
+                file: path.to_path_buf(),
+                error: array_error,
+            })
+        }
+        Err(_) => Err(LoadError::Json { file: path.to_path_buf(), error }),
     }
+}
 
-    for entry in fs::read_dir(path)? {
-        let entry = entry?;
-        let file_path = entry.path();
+/// The JSON Schema describing the registry file format.
+#[cfg(feature = "jsonschema")]
+pub const REGISTRY_SCHEMA: &str = include_str!("registry_schema.json");
 
-        if file_path.extension().is_some_and(|ext| ext == "json") {
-            match load_single_file(&file_path) {
-                Ok(file_values) => values.extend(file_values),
-                Err(e) => errors.push((file_path, e)),
+/// Validates registry content against [`REGISTRY_SCHEMA`].
+#[cfg(feature = "jsonschema")]
+fn validate_registry_schema(
+    content: &str,
+    path: &Path,
+) -> Result<(), LoadError> {
+    static VALIDATOR: std::sync::OnceLock<jsonschema::Validator> =
+        std::sync::OnceLock::new();
+    let validator = VALIDATOR.get_or_init(|| {
+        let schema = serde_json::from_str(REGISTRY_SCHEMA)
+            .expect("bundled registry schema is valid JSON");
+        jsonschema::validator_for(&schema)
+            .expect("bundled registry schema is a valid JSON Schema")
+    });
+
+    let instance: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| LoadError::Json { file: path.to_path_buf(), error: e })?;
+    let errors: Vec<String> = validator
+        .iter_errors(&instance)
+        .map(|error| format!("{}: {}", error.instance_path(), error))
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(LoadError::SchemaViolation { file: path.to_path_buf(), errors })
+    }
+}
+
+/// Removes `//` line comments and `/* */` block comments from JSON text.
+///
+/// Comments inside string literals are left untouched. Each removed comment
+/// character is replaced with a space (newlines are kept), so line and column
+/// numbers in subsequent parse errors still match the original file.
+#[cfg(feature = "jsonc")]
+fn strip_json_comments(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        output.push(escaped);
+                    }
+                }
+                '"' => in_string = false,
+                _ => {}
             }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                output.push(c);
+            }
+            ('/', Some('/')) => {
+                output.push_str("  ");
+                chars.next();
+                while let Some(&next) = chars.peek() {
+                    if next == '\n' {
+                        break;
+                    }
+                    output.push(' ');
+                    chars.next();
+                }
+            }
+            ('/', Some('*')) => {
+                output.push_str("  ");
+                chars.next();
+                while let Some(next) = chars.next() {
+                    if next == '*' && chars.peek() == Some(&'/') {
+                        chars.next();
+                        output.push_str("  ");
+                        break;
+                    }
+                    output.push(if next == '\n' { '\n' } else { ' ' });
+                }
+            }
+            _ => output.push(c),
         }
     }
 
-    Ok((values, errors))
+    output
 }
 
-/// Loads known values from a single JSON file.
-fn load_single_file(path: &Path) -> Result<Vec<KnownValue>, LoadError> {
-    let content = fs::read_to_string(path)?;
-    let registry: RegistryFile = serde_json::from_str(&content)
-        .map_err(|e| LoadError::Json { file: path.to_path_buf(), error: e })?;
+/// Removes commas that directly precede a closing `]` or `}` in JSON text.
+///
+/// Commas inside string literals are left untouched. Each removed comma is
+/// replaced with a space, so line and column numbers in subsequent parse
+/// errors still match the original file.
+fn strip_trailing_commas(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut in_string = false;
+    let mut escaped = false;
 
-    Ok(registry
-        .entries
-        .into_iter()
-        .map(|entry| KnownValue::new_with_name(entry.codepoint, entry.name))
-        .collect())
+    for (index, c) in content.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ','
+            && content[index + 1..].trim_start().starts_with([']', '}'])
+        {
+            output.push(' ');
+            continue;
+        }
+        output.push(c);
+    }
+
+    output
 }
 
 // Global configuration state
 static CUSTOM_CONFIG: Mutex<Option<DirectoryConfig>> = Mutex::new(None);
 static CONFIG_LOCKED: AtomicBool = AtomicBool::new(false);
 
-/// Error returned when configuration cannot be modified.
+/// Error returned when configuration cannot be modified or read.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigError {
     /// Configuration was attempted after the global registry was initialized.
     AlreadyInitialized,
+    /// A configuration file could not be read or parsed.
+    InvalidConfigFile {
+        /// The configuration file.
+        file: PathBuf,
+        /// A description of the problem.
+        message: String,
+    },
 }
 
 impl fmt::Display for ConfigError {
@@ -427,6 +2509,14 @@ impl fmt::Display for ConfigError {
                     "Cannot modify directory configuration after KNOWN_VALUES has been accessed"
                 )
             }
+            ConfigError::InvalidConfigFile { file, message } => {
+                write!(
+                    f,
+                    "Invalid configuration file {}: {}",
+                    file.display(),
+                    message
+                )
+            }
         }
     }
 }
@@ -438,6 +2528,8 @@ impl std::error::Error for ConfigError {}
 /// This function must be called **before** the first access to `KNOWN_VALUES`.
 /// Once `KNOWN_VALUES` is accessed, the configuration is locked and cannot
 /// be changed.
+/// Services that must reconfigure at runtime can use
+/// [`force_set_directory_config`] instead.
 ///
 /// # Arguments
 ///
@@ -472,6 +2564,97 @@ pub fn set_directory_config(
     Ok(())
 }
 
+/// Replaces the directory configuration, even after `KNOWN_VALUES` has been
+/// accessed.
+///
+/// This is an advanced escape hatch for long-running services that need to
+/// reconfigure at runtime. Unlike [`set_directory_config`], it ignores the
+/// initialization lock. The new configuration does not affect the global
+/// registry until [`reload_known_values`] is called.
+///
+/// This function is thread-safe: the configuration is swapped under a lock,
+/// and a concurrent reload observes either the old configuration or the new
+/// one. Reloads run one at a time, so a reload that observed the old
+/// configuration cannot replace the registry built by a later reload.
+///
+/// # Returns
+///
+/// Currently always returns `Ok(())`; the `Result` mirrors
+/// [`set_directory_config`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use known_values::{
+///     DirectoryConfig, force_set_directory_config, reload_known_values,
+/// };
+///
+/// force_set_directory_config(DirectoryConfig::with_paths(vec![
+///     "/etc/known-values".into(),
+/// ]))?;
+/// let result = reload_known_values()?;
+/// println!("Reloaded {} values", result.values_count());
+/// ```
+pub fn force_set_directory_config(
+    config: DirectoryConfig,
+) -> Result<(), ConfigError> {
+    *CUSTOM_CONFIG.lock().unwrap() = Some(config);
+    Ok(())
+}
+
+/// Rebuilds the global `KNOWN_VALUES` registry from the hardcoded values and
+/// the current directory configuration.
+///
+/// The new registry is built in full before being swapped in, so concurrent
+/// readers never observe a partially loaded registry. If the registry has
+/// not been accessed yet, it is initialized first. Use together with
+/// [`force_set_directory_config`] to change search paths at runtime.
+///
+/// If the load reports more non-fatal errors than
+/// [`DirectoryConfig::max_reload_errors`] allows (none by default), the
+/// previous registry is kept and a [`ReloadError`] carrying the rejected
+/// load is returned. This keeps a broken file from wiping the registry.
+///
+/// Returns the [`LoadResult`] of the directory load, so that callers can
+/// inspect any tolerated errors.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use known_values::reload_known_values;
+///
+/// match reload_known_values() {
+///     Ok(result) => println!("Reloaded {} values", result.values_count()),
+///     Err(error) => eprintln!("Keeping previous registry: {}", error),
+/// }
+/// ```
+pub fn reload_known_values() -> Result<LoadResult, ReloadError> {
+    crate::KNOWN_VALUES.reload()
+}
+
+/// Error returned by [`reload_known_values`] when a reload is rejected and
+/// the previous registry is kept.
+#[derive(Debug)]
+pub struct ReloadError {
+    /// The rejected load, including the errors that caused the rejection.
+    pub result: LoadResult,
+    /// The number of errors the configuration tolerates.
+    pub max_errors: usize,
+}
+
+impl fmt::Display for ReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Reload rejected: {} errors exceed the limit of {}",
+            self.result.errors.len(),
+            self.max_errors
+        )
+    }
+}
+
+impl std::error::Error for ReloadError {}
+
 /// Adds additional search paths to the directory configuration.
 ///
 /// This function must be called **before** the first access to `KNOWN_VALUES`.
@@ -513,17 +2696,39 @@ pub fn add_search_paths(paths: Vec<PathBuf>) -> Result<(), ConfigError> {
     Ok(())
 }
 
+/// Returns true if the global registry has been initialized, after which the
+/// directory configuration can no longer be changed.
+///
+/// This lets callers skip reconfiguration gracefully instead of calling
+/// [`set_directory_config`] and handling
+/// [`ConfigError::AlreadyInitialized`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use known_values::{DirectoryConfig, is_initialized, set_directory_config};
+///
+/// if is_initialized() {
+///     eprintln!("Known values already loaded; skipping reconfiguration");
+/// } else {
+///     set_directory_config(DirectoryConfig::default_only()).unwrap();
+/// }
+/// ```
+pub fn is_initialized() -> bool { CONFIG_LOCKED.load(Ordering::SeqCst) }
+
 /// Gets the current directory configuration, locking it for future
 /// modifications.
 ///
-/// This is called internally during `KNOWN_VALUES` initialization.
+/// This is called internally during `KNOWN_VALUES` initialization and
+/// reload. The configuration is kept, so that a reload without a new
+/// configuration uses the same search paths.
 pub(crate) fn get_and_lock_config() -> DirectoryConfig {
     CONFIG_LOCKED.store(true, Ordering::SeqCst);
     CUSTOM_CONFIG
         .lock()
         .unwrap()
-        .take()
-        .unwrap_or_else(DirectoryConfig::default_only)
+        .get_or_insert_with(DirectoryConfig::default_only)
+        .clone()
 }
 
 #[cfg(test)]
@@ -579,6 +2784,301 @@ mod tests {
         assert!(entry.description.is_some());
     }
 
+    #[test]
+    fn test_parse_bare_array_registry() {
+        let enveloped = r#"{"entries": [
+            {"codepoint": 1, "name": "first"},
+            {"codepoint": 2, "name": "second", "type": "class"}
+        ]}"#;
+        let bare = r#"[
+            {"codepoint": 1, "name": "first"},
+            {"codepoint": 2, "name": "second", "type": "class"}
+        ]"#;
+
+        let path = Path::new("test.json");
+        let from_envelope =
+            parse_registry_file(enveloped, path, false).unwrap().entries;
+        let from_array =
+            parse_registry_file(bare, path, false).unwrap().entries;
+        assert_eq!(from_envelope.len(), 2);
+        assert_eq!(from_array.len(), 2);
+        for (a, b) in from_envelope.iter().zip(&from_array) {
+            assert_eq!(a.codepoint, b.codepoint);
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.entry_type, b.entry_type);
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_bare_array_reports_array_error() {
+        let bare = r#"[{"codepoint": "one", "name": "bad"}]"#;
+        let err = parse_registry_file(bare, Path::new("bad.json"), false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("bad.json"));
+        assert!(!err.contains("RegistryFile"));
+    }
+
+    #[cfg(feature = "jsonc")]
+    #[test]
+    fn test_parse_registry_with_comments() {
+        let json = r#"// Leading comment describing the registry
+        {
+            /* The entries array */
+            "entries": [
+                // A line comment inside the array
+                {"codepoint": 1, "name": "first"}, // trailing line comment
+                /* block
+                   comment */
+                {"codepoint": 2, "name": "second", "uri": "https://example.com/a//b/*c*/"}
+            ]
+        }
+        // Trailing comment
+        /* and a trailing block comment */"#;
+
+        let entries = parse_registry_file(json, Path::new("test.json"), false)
+            .unwrap()
+            .entries;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "first");
+        assert_eq!(entries[1].name, "second");
+        assert_eq!(
+            entries[1].uri.as_deref(),
+            Some("https://example.com/a//b/*c*/")
+        );
+    }
+
+    #[test]
+    fn test_registry_entry_validate() {
+        let valid = RegistryEntry {
+            codepoint: 1000,
+            name: "my_Value2".to_string(),
+            entry_type: None,
+            uri: Some("urn:example:my-value".to_string()),
+            description: None,
+            deprecated: false,
+            names_by_lang: BTreeMap::new(),
+        };
+        assert_eq!(valid.validate(), Ok(()));
+        assert_eq!(
+            RegistryEntry { uri: None, ..valid.clone() }.validate(),
+            Ok(())
+        );
+
+        for name in ["", "2fast", "has space", "dash-name", "caf\u{e9}"] {
+            let entry =
+                RegistryEntry { name: name.to_string(), ..valid.clone() };
+            assert_eq!(
+                entry.validate(),
+                Err(EntryError::InvalidName { name: name.to_string() })
+            );
+        }
+
+        let zero = RegistryEntry { codepoint: 0, ..valid.clone() };
+        assert_eq!(zero.validate(), Err(EntryError::ZeroCodepoint));
+
+        for uri in [
+            "example.com",
+            ":no-scheme",
+            "https:",
+            "1http://x",
+            "a b:c",
+            "https://a b",
+        ] {
+            let entry =
+                RegistryEntry { uri: Some(uri.to_string()), ..valid.clone() };
+            assert_eq!(
+                entry.validate(),
+                Err(EntryError::InvalidUri { uri: uri.to_string() })
+            );
+        }
+    }
+
+    #[test]
+    fn test_trailing_commas_require_lenient_parsing() {
+        let path = Path::new("commas.json");
+        let in_array = r#"{"entries": [
+            {"codepoint": 1, "name": "first"},
+            {"codepoint": 2, "name": "second"},
+        ]}"#;
+        let in_entry = r#"[{"codepoint": 1, "name": "a,]", "uri": "x" , }]"#;
+
+        for json in [in_array, in_entry] {
+            assert!(matches!(
+                parse_registry_file(json, path, false),
+                Err(LoadError::Json { .. })
+            ));
+            assert!(parse_registry_file(json, path, true).is_ok());
+        }
+        let entries =
+            parse_registry_file(in_entry, path, true).unwrap().entries;
+        assert_eq!(entries[0].name, "a,]");
+        assert_eq!(entries[0].uri.as_deref(), Some("x"));
+
+        let stripped = strip_trailing_commas(in_array);
+        assert_eq!(stripped.len(), in_array.len());
+        assert_eq!(stripped.matches(',').count(), 3);
+    }
+
+    #[cfg(feature = "jsonc")]
+    #[test]
+    fn test_strip_json_comments_preserves_strings_and_lines() {
+        let json = "{\"a\": \"x // \\\" /* y\"} // z\n/* w\n */";
+        let stripped = strip_json_comments(json);
+        assert_eq!(stripped.len(), json.len());
+        assert_eq!(stripped.lines().count(), json.lines().count());
+        assert_eq!(stripped.trim_end(), "{\"a\": \"x // \\\" /* y\"}");
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[test]
+    fn test_schema_validation() {
+        let path = Path::new("schema.json");
+        let valid = r#"{
+            "$schema": "https://example.com/registry.schema.json",
+            "entries": [{"codepoint": 1, "name": "valid", "deprecated": false}]
+        }"#;
+        assert_eq!(
+            parse_registry_file(valid, path, false)
+                .unwrap()
+                .entries
+                .len(),
+            1
+        );
+        let bare = r#"[{"codepoint": 1, "name": "valid"}]"#;
+        assert_eq!(
+            parse_registry_file(bare, path, false)
+                .unwrap()
+                .entries
+                .len(),
+            1
+        );
+
+        let invalid = r#"{"entries": [{"codepoint": "one", "name": "bad"}]}"#;
+        match parse_registry_file(invalid, path, false) {
+            Err(LoadError::SchemaViolation { file, errors }) => {
+                assert_eq!(file, path);
+                assert_eq!(errors.len(), 1);
+                assert!(errors[0].starts_with("/entries/0/codepoint: "));
+            }
+            other => panic!("expected schema violation, got {:?}", other),
+        }
+
+        let missing_name = r#"[{"codepoint": 1}]"#;
+        assert!(matches!(
+            parse_registry_file(missing_name, path, false),
+            Err(LoadError::SchemaViolation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_to_json_with_sort_orders() {
+        let json = r#"[
+            {"codepoint": 20, "name": "bravo"},
+            {"codepoint": 30, "name": "alpha"},
+            {"codepoint": 10, "name": "charlie"},
+            {"codepoint": 5, "name": "alpha"}
+        ]"#;
+        let path = Path::new("order.json");
+        let registry = parse_registry_file(json, path, false).unwrap();
+
+        let order = |sort_order| {
+            let json = registry.to_json_with(sort_order);
+            parse_registry_file(&json, path, false)
+                .unwrap()
+                .entries
+                .iter()
+                .map(|entry| entry.codepoint)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(order(SortOrder::ByCodepoint), vec![5, 10, 20, 30]);
+        assert_eq!(order(SortOrder::ByName), vec![5, 30, 20, 10]);
+        assert_eq!(order(SortOrder::AsGiven), vec![20, 30, 10, 5]);
+        assert_eq!(
+            registry.to_json_with(SortOrder::default()),
+            registry.to_canonical_json()
+        );
+    }
+
+    #[test]
+    fn test_canonical_json_round_trip() {
+        let json = r#"{
+            "statistics": {"total": 3},
+            "entries": [
+                {"name": "third", "codepoint": 3, "deprecated": true},
+                {"codepoint": 1, "name": "first", "uri": "https://example.com",
+                 "names_by_lang": {"fr": "premier", "en": "first"}},
+                {"description": "The second", "codepoint": 2, "name": "second"}
+            ],
+            "ontology": {"processing_strategy": "manual", "name": "test"},
+            "$schema": "https://example.com/s.json"
+        }"#;
+        let path = Path::new("canonical.json");
+        let registry = parse_registry_file(json, path, false).unwrap();
+
+        let canonical = registry.to_canonical_json();
+        let reparsed = parse_registry_file(&canonical, path, false).unwrap();
+        let codepoints: Vec<_> = reparsed
+            .entries
+            .iter()
+            .map(|entry| entry.codepoint)
+            .collect();
+        assert_eq!(codepoints, vec![1, 2, 3]);
+
+        let mut sorted = registry;
+        sorted.entries.sort_by_key(|entry| entry.codepoint);
+        assert_eq!(reparsed, sorted);
+        assert_eq!(reparsed.to_canonical_json(), canonical);
+
+        let schema = canonical.find("$schema").unwrap();
+        let ontology = canonical.find("ontology").unwrap();
+        let entries = canonical.find("entries").unwrap();
+        assert!(schema < ontology && ontology < entries);
+        assert!(
+            canonical.find("\"en\"").unwrap()
+                < canonical.find("\"fr\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unknown_top_level_fields_round_trip() {
+        let json = r#"{
+            "entries": [{"codepoint": 1, "name": "first"}],
+            "signatures": [{"key": "abc", "sig": "def"}],
+            "format_version": 2
+        }"#;
+        let path = Path::new("extra.json");
+        let registry = parse_registry_file(json, path, false).unwrap();
+        assert_eq!(registry.entries.len(), 1);
+        assert_eq!(registry.extra.len(), 2);
+        assert_eq!(registry.extra["format_version"], 2);
+        assert_eq!(registry.extra["signatures"][0]["key"], "abc");
+
+        let reparsed =
+            parse_registry_file(&registry.to_canonical_json(), path, false)
+                .unwrap();
+        assert_eq!(reparsed, registry);
+
+        let bare = parse_registry_file(
+            r#"[{"codepoint": 1, "name": "a"}]"#,
+            path,
+            false,
+        )
+        .unwrap();
+        assert!(bare.extra.is_empty());
+    }
+
+    #[test]
+    fn test_parse_registry_schema_field() {
+        let json =
+            r#"{"$schema": "https://example.com/s.json", "entries": []}"#;
+        let registry: RegistryFile = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            registry.schema.as_deref(),
+            Some("https://example.com/s.json")
+        );
+    }
+
     #[test]
     fn test_directory_config_default() {
         let config = DirectoryConfig::default_only();
@@ -608,6 +3108,91 @@ mod tests {
         assert!(config.paths()[1].ends_with(".known-values"));
     }
 
+    #[test]
+    fn test_directory_config_fail_fast() {
+        let config = DirectoryConfig::new();
+        assert!(!config.is_fail_fast());
+        assert!(config.fail_fast(true).is_fail_fast());
+        assert!(!DirectoryConfig::default_only().is_fail_fast());
+    }
+
+    #[test]
+    fn test_directory_config_prepend_path() {
+        let mut config = DirectoryConfig::with_paths(vec![PathBuf::from("/a")]);
+        config.prepend_path(PathBuf::from("/b"));
+        assert_eq!(config.paths(), [PathBuf::from("/b"), PathBuf::from("/a")]);
+        assert_eq!(config.get_merge_strategy(), MergeStrategy::LastWins);
+    }
+
+    #[test]
+    fn test_directory_config_clear_and_set_paths() {
+        let mut config = DirectoryConfig::default().fail_fast(true);
+        config.clear_paths();
+        assert!(config.paths().is_empty());
+        assert!(config.is_fail_fast());
+
+        config.set_paths(vec![PathBuf::from("/x"), PathBuf::from("/y")]);
+        assert_eq!(config.paths(), [PathBuf::from("/x"), PathBuf::from("/y")]);
+
+        config.set_paths(vec![PathBuf::from("/z")]);
+        assert_eq!(config.paths(), [PathBuf::from("/z")]);
+    }
+
+    #[test]
+    fn test_expand_tilde_path() {
+        let home = Some(PathBuf::from("/home/user"));
+        let no_vars = |_: &str| None;
+        let expand = |path: &str| {
+            expand_path_with(Path::new(path), home.clone(), no_vars)
+        };
+
+        assert_eq!(
+            expand("~/.known-values"),
+            Path::new("/home/user/.known-values")
+        );
+        assert_eq!(expand("~"), Path::new("/home/user"));
+        assert_eq!(expand("~user/x"), Path::new("~user/x"));
+        assert_eq!(expand("/etc/~"), Path::new("/etc/~"));
+        assert_eq!(
+            expand_path_with(Path::new("~/x"), None, no_vars),
+            Path::new("~/x")
+        );
+    }
+
+    #[test]
+    fn test_expand_env_var_path() {
+        let vars = HashMap::from([
+            ("REGISTRY_DIR", "/srv/known-values"),
+            ("SUBDIR", "extra"),
+            ("TILDE", "~"),
+        ]);
+        let expand = |path: &str| {
+            expand_path_with(
+                Path::new(path),
+                Some("/home/user".into()),
+                |name| vars.get(name).map(|value| value.to_string()),
+            )
+        };
+
+        assert_eq!(
+            expand("$REGISTRY_DIR/registry"),
+            Path::new("/srv/known-values/registry")
+        );
+        assert_eq!(
+            expand("${REGISTRY_DIR}/registry"),
+            Path::new("/srv/known-values/registry")
+        );
+        assert_eq!(
+            expand("$REGISTRY_DIR/${SUBDIR}"),
+            Path::new("/srv/known-values/extra")
+        );
+        assert_eq!(expand("$TILDE/x"), Path::new("/home/user/x"));
+        assert_eq!(expand("/a/$UNSET_VAR/b"), Path::new("/a/$UNSET_VAR/b"));
+        assert_eq!(expand("/a/${oops/b"), Path::new("/a/${oops/b"));
+        assert_eq!(expand("/a/$/b"), Path::new("/a/$/b"));
+        assert_eq!(expand("/a/${}/b"), Path::new("/a/${}/b"));
+    }
+
     #[test]
     fn test_load_from_nonexistent_directory() {
         let result = load_from_directory(Path::new("/nonexistent/path/12345"));
@@ -626,4 +3211,179 @@ mod tests {
             .insert(1, KnownValue::new_with_name(1u64, "test".to_string()));
         assert_eq!(result.values_count(), 1);
     }
+
+    #[test]
+    fn test_dedup_names() {
+        let mut result = LoadResult::default();
+        for (codepoint, name) in [
+            (300u64, "token"),
+            (100, "token"),
+            (200, "token"),
+            (400, "token_2"),
+            (500, "unique"),
+        ] {
+            result.values.insert(
+                codepoint,
+                KnownValue::new_with_name(codepoint, name.to_string())
+                    .with_uri(format!("https://example.com/{}", codepoint)),
+            );
+        }
+
+        let reported = result.dedup_names(DedupStrategy::ReportOnly);
+        assert_eq!(
+            reported,
+            vec![NameCollision {
+                name: "token".to_string(),
+                codepoints: vec![100, 200, 300],
+            }]
+        );
+        assert_eq!(result.values[&300].name(), "token");
+
+        assert_eq!(result.dedup_names(DedupStrategy::Suffix), reported);
+        assert_eq!(result.values[&100].name(), "token");
+        assert_eq!(result.values[&200].name(), "token_3");
+        assert_eq!(result.values[&300].name(), "token_4");
+        assert_eq!(result.values[&400].name(), "token_2");
+        assert_eq!(result.values[&300].uri(), Some("https://example.com/300"));
+        assert!(result.dedup_names(DedupStrategy::Suffix).is_empty());
+
+        let store = result.into_store();
+        assert_eq!(store.codepoint_of("token"), Some(100));
+        assert_eq!(store.codepoint_of("token_4"), Some(300));
+    }
+
+    #[test]
+    fn test_exe_relative_paths() {
+        let base = tempfile::tempdir().unwrap();
+        let registry = base.path().join("known-values");
+        fs::create_dir(&registry).unwrap();
+        fs::write(
+            registry.join("bundled.json"),
+            r#"{"entries": [{"codepoint": 76001, "name": "bundledValue"}]}"#,
+        )
+        .unwrap();
+
+        let config = DirectoryConfig::with_exe_relative("known-values");
+        assert!(config.paths().is_empty());
+        let result =
+            load_from_config_with_exe_dir(&config, false, false, || {
+                Ok(base.path().to_path_buf())
+            })
+            .unwrap();
+        assert_eq!(result.values[&76001].name(), "bundledValue");
+        assert_eq!(result.files_processed, vec![registry.clone()]);
+
+        // A missing executable location skips the path with an error.
+        let missing = || Err(io::Error::other("no executable"));
+        let result =
+            load_from_config_with_exe_dir(&config, false, false, missing)
+                .unwrap();
+        assert!(result.values.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].0, PathBuf::from("known-values"));
+        assert!(matches!(
+            load_from_config_with_exe_dir(&config, true, false, missing),
+            Err(LoadError::Io(_))
+        ));
+
+        let mut config = DirectoryConfig::builder()
+            .exe_relative_path("known-values")
+            .build();
+        assert_eq!(
+            config.exe_relative_paths(),
+            [PathBuf::from("known-values")]
+        );
+        config.clear_paths();
+        assert!(config.exe_relative_paths().is_empty());
+    }
+
+    #[test]
+    fn test_directory_config_equality_and_hash() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            hash::{Hash, Hasher},
+        };
+
+        fn hash_of(config: &DirectoryConfig) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            config.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let paths = vec![PathBuf::from("/a"), PathBuf::from("/b")];
+        let config = DirectoryConfig::with_paths(paths.clone());
+        let same = DirectoryConfig::with_paths(paths.clone());
+        assert_eq!(config, same);
+        assert_eq!(hash_of(&config), hash_of(&same));
+
+        let handled = same.with_error_handler(Box::new(|_, _| {}));
+        assert_eq!(config, handled);
+        assert_eq!(hash_of(&config), hash_of(&handled));
+
+        assert_ne!(config, DirectoryConfig::with_paths(vec![paths[0].clone()]));
+        assert_ne!(config, config.clone().fail_fast(true));
+        assert_ne!(
+            config,
+            config.clone().merge_strategy(MergeStrategy::FirstWins)
+        );
+        assert_ne!(config, config.clone().use_manifest("index.json"));
+
+        let mut cache = HashMap::new();
+        cache.insert(config.clone(), 1);
+        assert_eq!(cache.get(&DirectoryConfig::with_paths(paths)), Some(&1));
+    }
+
+    #[test]
+    fn test_xdg_paths() {
+        let data = PathBuf::from("/home/user/.local/share");
+        let config = PathBuf::from("/home/user/.config");
+        assert_eq!(
+            xdg_paths(Some(data.clone()), Some(config.clone())),
+            vec![
+                PathBuf::from("/home/user/.local/share/known-values"),
+                PathBuf::from("/home/user/.config/known-values"),
+            ]
+        );
+        assert_eq!(
+            xdg_paths(None, Some(config)),
+            vec![PathBuf::from("/home/user/.config/known-values")]
+        );
+        assert_eq!(
+            xdg_paths(Some(data), None),
+            vec![PathBuf::from("/home/user/.local/share/known-values")]
+        );
+        assert!(xdg_paths(None, None).is_empty());
+
+        // macOS and Windows use one directory for both
+        let shared = PathBuf::from("/Users/user/Library/Application Support");
+        assert_eq!(
+            xdg_paths(Some(shared.clone()), Some(shared)),
+            vec![PathBuf::from(
+                "/Users/user/Library/Application Support/known-values"
+            )]
+        );
+
+        let xdg = DirectoryConfig::xdg();
+        let with_default = DirectoryConfig::xdg_and_default();
+        assert_eq!(with_default.paths().len(), xdg.paths().len() + 1);
+        assert_eq!(&with_default.paths()[..xdg.paths().len()], xdg.paths());
+        assert_eq!(
+            with_default.paths().last(),
+            Some(&DirectoryConfig::default_directory())
+        );
+    }
+
+    #[test]
+    fn test_schema_violation_without_feature() {
+        // The variant is available whether or not `jsonschema` is enabled
+        let error = LoadError::SchemaViolation {
+            file: PathBuf::from("registry.json"),
+            errors: vec!["/entries/0: missing name".to_string()],
+        };
+        assert_eq!(
+            error.to_string(),
+            "Schema violation in registry.json: /entries/0: missing name"
+        );
+        assert!(std::error::Error::source(&error).is_none());
+    }
 }
@@ -1,12 +1,46 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter, LowerHex},
+    sync::{Arc, Mutex, OnceLock},
+};
 
 use bc_components::{Digest, DigestProvider, tags};
 use dcbor::prelude::*;
 
+use crate::Category;
+
 #[derive(Debug, Clone)]
 enum KnownValueName {
     Static(&'static str),
     Dynamic(String),
+    Interned(Arc<str>),
+}
+
+/// Returns the shared copy of `name` from the global intern pool, adding it
+/// on first use.
+fn intern(name: &str) -> Arc<str> {
+    static INTERNED_NAMES: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+    let mut names = INTERNED_NAMES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(interned) = names.get(name) {
+        return interned.clone();
+    }
+    let interned: Arc<str> = Arc::from(name);
+    names.insert(interned.clone());
+    interned
+}
+
+/// Optional descriptive metadata carried by a KnownValue, typically populated
+/// from a registry file.
+#[derive(Debug, Clone, Default)]
+struct KnownValueMetadata {
+    entry_type: Option<String>,
+    uri: Option<String>,
+    description: Option<String>,
+    deprecated: bool,
+    localized_names: HashMap<String, String>,
 }
 
 /// A value in a namespace of unsigned integers that represents a stand-alone
@@ -53,6 +87,14 @@ enum KnownValueName {
 /// assert_eq!(is_a_value.name(), "isA");
 /// ```
 ///
+/// # CBOR Encoding
+///
+/// Known Values are encoded as an unsigned integer tagged with the
+/// standard Known Value tag, **#6.40000** (`tags::TAG_KNOWN_VALUE`). Use
+/// [`KnownValue::to_cbor_with_tag`] and [`KnownValue::from_cbor_with_tag`] to
+/// encode and decode with a different tag, e.g. for experimental envelope
+/// variants.
+///
 /// # Specification
 ///
 /// Known Values are defined in
@@ -66,11 +108,43 @@ pub struct KnownValue {
     /// A name assigned to the known value used for debugging and formatted
     /// output.
     assigned_name: Option<KnownValueName>,
+    /// Descriptive metadata such as the entry type, URI, and description.
+    metadata: Option<Box<KnownValueMetadata>>,
+    /// True for placeholders created by [`KnownValue::unresolved`].
+    unresolved: bool,
 }
 
 impl KnownValue {
+    /// The codepoint carried by unresolved KnownValues.
+    ///
+    /// Placeholders are marked separately from their codepoint, so a
+    /// KnownValue that merely has this codepoint, e.g. one decoded from CBOR,
+    /// is still resolved. Use [`KnownValue::is_resolved`] rather than
+    /// comparing codepoints.
+    pub const UNRESOLVED: u64 = u64::MAX;
+
+    /// A bare KnownValue with codepoint 0, for use as a "no value" sentinel.
+    ///
+    /// The specification reserves codepoint 0, so it never identifies an
+    /// ordinary concept; the registry's [`UNIT`](crate::UNIT) occupies it
+    /// with an empty name. Since equality compares codepoints only, this
+    /// constant is equal to `UNIT`. See [`KnownValue::is_unassigned`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(KnownValue::UNASSIGNED.value(), 0);
+    /// assert_eq!(KnownValue::UNASSIGNED.assigned_name(), None);
+    /// ```
+    pub const UNASSIGNED: KnownValue = KnownValue::new(0);
+
     /// Creates a new KnownValue with the given numeric value and no name.
     ///
+    /// This is a `const fn`, so ad-hoc known values can be declared as
+    /// constants without the `const_known_value!` macro.
+    ///
     /// # Examples
     ///
     /// ```
@@ -78,8 +152,100 @@ impl KnownValue {
     ///
     /// let known_value = KnownValue::new(42);
     /// assert_eq!(known_value.value(), 42);
+    ///
+    /// const MY_VALUE: KnownValue = KnownValue::new(1234);
+    /// assert_eq!(MY_VALUE.value(), 1234);
+    /// ```
+    pub const fn new(value: u64) -> Self {
+        Self { value, assigned_name: None, metadata: None, unresolved: false }
+    }
+
+    /// Creates a new KnownValue with no name, rejecting values above `max`.
+    ///
+    /// This guards protocols that cap known value codepoints, e.g. to fit a
+    /// smaller integer field. It is unrelated to the category blocks of the
+    /// registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{CodepointOutOfRange, KnownValue};
+    ///
+    /// let max = u64::from(u16::MAX);
+    /// assert_eq!(
+    ///     KnownValue::try_new_bounded(1000, max).unwrap().value(),
+    ///     1000
+    /// );
+    /// assert_eq!(
+    ///     KnownValue::try_new_bounded(70_000, max),
+    ///     Err(CodepointOutOfRange { value: 70_000, max })
+    /// );
+    /// ```
+    pub fn try_new_bounded(
+        value: u64,
+        max: u64,
+    ) -> Result<Self, CodepointOutOfRange> {
+        if value > max {
+            return Err(CodepointOutOfRange { value, max });
+        }
+        Ok(Self::new(value))
+    }
+
+    /// Creates a placeholder KnownValue that carries only a name, for
+    /// documents that reference values by name before a store is available.
+    ///
+    /// The codepoint is set to [`KnownValue::UNRESOLVED`], and the value is
+    /// marked as a placeholder. Use [`KnownValuesStore::resolve_unresolved`]
+    /// to look up the real codepoint once a store is available.
+    ///
+    /// [`KnownValuesStore::resolve_unresolved`]: crate::KnownValuesStore::resolve_unresolved
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let placeholder = KnownValue::unresolved("isA");
+    /// assert!(!placeholder.is_resolved());
+    /// assert_eq!(placeholder.value(), KnownValue::UNRESOLVED);
+    /// assert_eq!(placeholder.assigned_name(), Some("isA"));
+    /// ```
+    pub fn unresolved(name: impl Into<String>) -> Self {
+        Self {
+            unresolved: true,
+            ..Self::new_with_name(Self::UNRESOLVED, name.into())
+        }
+    }
+
+    /// Returns true unless this is a placeholder created by
+    /// [`KnownValue::unresolved`].
+    ///
+    /// Any other KnownValue is resolved, including one whose codepoint
+    /// happens to be [`KnownValue::UNRESOLVED`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert!(known_values::IS_A.is_resolved());
+    /// assert!(!KnownValue::unresolved("isA").is_resolved());
+    /// assert!(KnownValue::new(u64::MAX).is_resolved());
+    /// ```
+    pub const fn is_resolved(&self) -> bool { !self.unresolved }
+
+    /// Returns true if the codepoint is 0, the reserved
+    /// [`KnownValue::UNASSIGNED`] codepoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert!(KnownValue::UNASSIGNED.is_unassigned());
+    /// assert!(!known_values::IS_A.is_unassigned());
     /// ```
-    pub fn new(value: u64) -> Self { Self { value, assigned_name: None } }
+    pub const fn is_unassigned(&self) -> bool { self.value == 0 }
 
     /// Creates a KnownValue with the given value and associated name.
     ///
@@ -102,6 +268,42 @@ impl KnownValue {
         Self {
             value: value.into(),
             assigned_name: Some(KnownValueName::Dynamic(assigned_name)),
+            metadata: None,
+            unresolved: false,
+        }
+    }
+
+    /// Creates a KnownValue whose name is shared through a global intern
+    /// pool.
+    ///
+    /// Every KnownValue interned with the same name points at the same
+    /// allocation, which saves memory when a few names recur many times, e.g.
+    /// when parsing documents with repeated references. Interned names are
+    /// never freed, so this is best suited to a bounded vocabulary.
+    ///
+    /// A [`KnownValuesStore`](crate::KnownValuesStore) keeps the shared name
+    /// of an interned value it stores, so values looked up from the store
+    /// share their name with these. Names of other values are not interned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let a = KnownValue::interned(1u64, "isA");
+    /// let b = KnownValue::interned(1u64, "isA");
+    /// assert_eq!(a.name(), "isA");
+    /// assert_eq!(
+    ///     a.assigned_name().unwrap().as_ptr(),
+    ///     b.assigned_name().unwrap().as_ptr()
+    /// );
+    /// ```
+    pub fn interned<T: Into<u64>>(value: T, name: &str) -> Self {
+        Self {
+            value: value.into(),
+            assigned_name: Some(KnownValueName::Interned(intern(name))),
+            metadata: None,
+            unresolved: false,
         }
     }
 
@@ -126,6 +328,8 @@ impl KnownValue {
         Self {
             value,
             assigned_name: Some(KnownValueName::Static(name)),
+            metadata: None,
+            unresolved: false,
         }
     }
 
@@ -139,7 +343,96 @@ impl KnownValue {
     /// assert_eq!(known_values::IS_A.value(), 1);
     /// assert_eq!(known_values::NOTE.value(), 4);
     /// ```
-    pub fn value(&self) -> u64 { self.value }
+    pub const fn value(&self) -> u64 { self.value }
+
+    /// Returns the numeric value of the KnownValue in hexadecimal, prefixed
+    /// with `0x`.
+    ///
+    /// The [`LowerHex`](std::fmt::LowerHex) implementation can be used
+    /// instead when padding or other formatting flags are needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(known_values::IS_A.value_hex(), "0x1");
+    /// assert_eq!(known_values::SELF.value_hex(), "0x2c2");
+    /// ```
+    pub fn value_hex(&self) -> String { format!("{:#x}", self.value) }
+
+    /// Returns the number of bytes needed to represent the codepoint as a
+    /// minimal big-endian unsigned integer, from 1 to 8.
+    ///
+    /// Codepoint 0 still needs one byte. This is a pure function of the
+    /// codepoint and is useful for estimating encoded sizes up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(KnownValue::new(0xFF).codepoint_byte_width(), 1);
+    /// assert_eq!(KnownValue::new(0x100).codepoint_byte_width(), 2);
+    /// assert_eq!(KnownValue::new(u64::MAX).codepoint_byte_width(), 8);
+    /// ```
+    pub const fn codepoint_byte_width(&self) -> u8 {
+        let bits = u64::BITS - self.value.leading_zeros();
+        if bits == 0 { 1 } else { bits.div_ceil(8) as u8 }
+    }
+
+    /// Returns a bare KnownValue whose codepoint is `n` past this one, or
+    /// `None` if the addition would overflow.
+    ///
+    /// The result carries no name, since names belong to the codepoint they
+    /// were assigned to. This is useful for allocating blocks of codepoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let next = known_values::IS_A.checked_add(1).unwrap();
+    /// assert_eq!(next.value(), 2);
+    /// assert_eq!(next.assigned_name(), None);
+    ///
+    /// assert_eq!(KnownValue::new(u64::MAX).checked_add(1), None);
+    /// ```
+    pub fn checked_add(&self, n: u64) -> Option<Self> {
+        self.value.checked_add(n).map(Self::new)
+    }
+
+    /// Returns the registry category block that contains this codepoint.
+    ///
+    /// The category depends only on the codepoint, not on whether a value is
+    /// actually assigned there. See [`Category`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{Category, KnownValue};
+    ///
+    /// assert_eq!(known_values::IS_A.category(), Category::General);
+    /// assert_eq!(KnownValue::new(550).category(), Category::Bitcoin);
+    /// assert_eq!(KnownValue::new(100_000).category(), Category::Unassigned);
+    /// ```
+    pub const fn category(&self) -> Category { Category::of(self.value) }
+
+    /// Returns the human-readable name of the registry category block that
+    /// contains this codepoint.
+    ///
+    /// This is shorthand for `self.category().name()`, e.g. `"Cryptography"`
+    /// for codepoints 200-299 and `"Unassigned"` past the last block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(known_values::IS_A.category_name(), "General");
+    /// assert_eq!(known_values::SEED_TYPE.category_name(), "Cryptography");
+    /// assert_eq!(known_values::NODE.category_name(), "Graphs");
+    /// assert_eq!(KnownValue::new(100_000).category_name(), "Unassigned");
+    /// ```
+    pub const fn category_name(&self) -> &'static str { self.category().name() }
 
     /// Returns the assigned name of the KnownValue, if one exists.
     ///
@@ -158,10 +451,302 @@ impl KnownValue {
         match &self.assigned_name {
             Some(KnownValueName::Static(name)) => Some(name),
             Some(KnownValueName::Dynamic(name)) => Some(name),
+            Some(KnownValueName::Interned(name)) => Some(name),
             None => None,
         }
     }
 
+    /// Returns the UTF-8 bytes of the assigned name, if one exists, without
+    /// allocating.
+    ///
+    /// Unlike [`KnownValue::name`], this does not synthesize the numeric
+    /// fallback: a value without an assigned name returns `None`, so callers
+    /// can decide how to encode it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(known_values::IS_A.name_bytes(), Some(&b"isA"[..]));
+    /// assert_eq!(KnownValue::new(42).name_bytes(), None);
+    /// ```
+    pub fn name_bytes(&self) -> Option<&[u8]> {
+        self.assigned_name().map(str::as_bytes)
+    }
+
+    /// Encodes the KnownValue as CBOR tagged with the given tag number
+    /// instead of the standard Known Value tag (40000).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dcbor::prelude::*;
+    /// use known_values::KnownValue;
+    ///
+    /// let cbor = known_values::IS_A.to_cbor_with_tag(99);
+    /// assert_eq!(cbor.diagnostic(), "99(1)");
+    ///
+    /// let decoded = KnownValue::from_cbor_with_tag(cbor, 99).unwrap();
+    /// assert_eq!(decoded, known_values::IS_A);
+    ///
+    /// // The default encoding uses the standard tag
+    /// let cbor = known_values::IS_A.to_cbor();
+    /// assert_eq!(cbor.diagnostic(), "40000(1)");
+    /// assert_eq!(
+    ///     KnownValue::from_cbor_with_tag(cbor, 40000).unwrap().value(),
+    ///     1
+    /// );
+    /// ```
+    pub fn to_cbor_with_tag(&self, tag: u64) -> CBOR {
+        CBOR::to_tagged_value(tag, self.untagged_cbor())
+    }
+
+    /// Decodes a KnownValue from CBOR tagged with the given tag number.
+    ///
+    /// Returns an error if the CBOR is not tagged, is tagged with a different
+    /// tag, or does not contain an unsigned integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let cbor = known_values::NOTE.to_cbor_with_tag(99);
+    /// assert_eq!(
+    ///     KnownValue::from_cbor_with_tag(cbor.clone(), 99)
+    ///         .unwrap()
+    ///         .value(),
+    ///     4
+    /// );
+    /// assert!(KnownValue::from_cbor_with_tag(cbor, 40000).is_err());
+    /// ```
+    pub fn from_cbor_with_tag(cbor: CBOR, tag: u64) -> dcbor::Result<Self> {
+        Self::from_untagged_cbor(cbor.try_into_expected_tagged_value(tag)?)
+    }
+
+    /// Decodes a KnownValue from raw dCBOR bytes tagged with the standard
+    /// Known Value tag (40000).
+    ///
+    /// Returns an error if the bytes are not valid dCBOR, contain data after
+    /// the encoded value, are tagged with a different tag, or do not contain
+    /// an unsigned integer. The decoded value has no assigned name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dcbor::prelude::*;
+    /// use known_values::KnownValue;
+    ///
+    /// let data = known_values::NOTE.to_cbor_data();
+    /// assert_eq!(KnownValue::from_cbor_data(&data).unwrap().value(), 4);
+    ///
+    /// let wrong_tag = known_values::NOTE.to_cbor_with_tag(99).to_cbor_data();
+    /// assert!(KnownValue::from_cbor_data(&wrong_tag).is_err());
+    /// ```
+    pub fn from_cbor_data(data: &[u8]) -> dcbor::Result<Self> {
+        Self::from_tagged_cbor(CBOR::try_from_data(data)?)
+    }
+
+    /// Returns the assigned name as an `Arc<str>`, or None if there is no
+    /// assigned name.
+    ///
+    /// An interned name is shared rather than copied. Other names are copied
+    /// into a fresh allocation and are not added to the intern pool.
+    pub(crate) fn shared_name(&self) -> Option<Arc<str>> {
+        match self.assigned_name.as_ref()? {
+            KnownValueName::Interned(name) => Some(name.clone()),
+            KnownValueName::Static(name) => Some(Arc::from(*name)),
+            KnownValueName::Dynamic(name) => Some(Arc::from(name.as_str())),
+        }
+    }
+
+    /// Replaces the assigned name, keeping the value and metadata.
+    pub(crate) fn with_assigned_name(mut self, name: Option<String>) -> Self {
+        self.assigned_name = name.map(KnownValueName::Dynamic);
+        self
+    }
+
+    /// Returns this value with its assigned name replaced by the result of
+    /// `f`, keeping the codepoint and metadata.
+    ///
+    /// The closure receives the current assigned name, if any, so it can
+    /// rewrite the name, clear it by returning `None`, or give a bare value
+    /// a name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let upper = known_values::NOTE.map_name(|name| name.map(str::to_uppercase));
+    /// assert_eq!(upper.assigned_name(), Some("NOTE"));
+    /// assert_eq!(upper.value(), 4);
+    ///
+    /// let bare = known_values::NOTE.map_name(|_| None);
+    /// assert_eq!(bare.assigned_name(), None);
+    ///
+    /// let named = KnownValue::new(1000)
+    ///     .map_name(|name| name.or(Some("custom")).map(String::from));
+    /// assert_eq!(named.assigned_name(), Some("custom"));
+    /// ```
+    pub fn map_name<F>(self, f: F) -> KnownValue
+    where
+        F: FnOnce(Option<&str>) -> Option<String>,
+    {
+        let name = f(self.assigned_name());
+        self.with_assigned_name(name)
+    }
+
+    /// Returns the assigned name of the KnownValue, or `fallback` if it has
+    /// none.
+    ///
+    /// Unlike [`KnownValue::name`], this never falls back to the numeric
+    /// value, which is useful for user-facing display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(known_values::IS_A.assigned_name_or("<unknown>"), "isA");
+    /// assert_eq!(
+    ///     KnownValue::new(42).assigned_name_or("<unknown>"),
+    ///     "<unknown>"
+    /// );
+    /// ```
+    pub fn assigned_name_or<'a>(&'a self, fallback: &'a str) -> &'a str {
+        self.assigned_name().unwrap_or(fallback)
+    }
+
+    /// Returns true if both KnownValues have the same codepoint, regardless
+    /// of their names.
+    ///
+    /// This is the comparison performed by `==`, spelled out for call sites
+    /// that want to make the "same concept" semantics explicit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let renamed = KnownValue::new_with_name(1u64, "IS_A".to_string());
+    /// assert!(known_values::IS_A.is_equivalent_ignoring_name(&renamed));
+    /// assert!(!known_values::IS_A.is_equivalent_ignoring_name(&known_values::ID));
+    /// ```
+    pub fn is_equivalent_ignoring_name(&self, other: &KnownValue) -> bool {
+        self.value == other.value
+    }
+
+    /// Returns true if both KnownValues have the same codepoint and the same
+    /// assigned name, and are either both resolved or both placeholders.
+    ///
+    /// Unlike `==`, which compares codepoints only, this distinguishes a
+    /// decoded value from a registry entry with a different name. Metadata is
+    /// not compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let renamed = KnownValue::new_with_name(1u64, "IS_A".to_string());
+    /// assert_eq!(known_values::IS_A, renamed);
+    /// assert!(!known_values::IS_A.is_exactly(&renamed));
+    /// assert!(
+    ///     known_values::IS_A
+    ///         .is_exactly(&KnownValue::new_with_name(1u64, "isA".to_string()))
+    /// );
+    /// ```
+    pub fn is_exactly(&self, other: &KnownValue) -> bool {
+        self.value == other.value
+            && self.assigned_name() == other.assigned_name()
+            && self.unresolved == other.unresolved
+    }
+
+    /// Returns true if `query` refers to this KnownValue under the given
+    /// [`MatchOptions`].
+    ///
+    /// The query matches if it equals the assigned name (compared according
+    /// to [`MatchOptions::case_sensitive`]), or, when enabled, if it is the
+    /// codepoint in decimal, optionally prefixed with `#`. A value without an
+    /// assigned name can only match numerically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, MatchOptions};
+    ///
+    /// let opts = MatchOptions::default();
+    /// assert!(known_values::IS_A.matches("isA", opts));
+    /// assert!(!known_values::IS_A.matches("isa", opts));
+    /// assert!(known_values::IS_A.matches("1", opts));
+    /// assert!(!known_values::IS_A.matches("#1", opts));
+    ///
+    /// let opts = MatchOptions { case_sensitive: false, ..opts };
+    /// assert!(known_values::IS_A.matches("ISA", opts));
+    ///
+    /// let opts = MatchOptions { numeric: false, hash_prefixed: true, ..opts };
+    /// assert!(!KnownValue::new(42).matches("42", opts));
+    /// assert!(KnownValue::new(42).matches("#42", opts));
+    /// ```
+    pub fn matches(&self, query: &str, opts: MatchOptions) -> bool {
+        if let Some(name) = self.assigned_name() {
+            let name_matches = if opts.case_sensitive {
+                name == query
+            } else {
+                name.eq_ignore_ascii_case(query)
+            };
+            if name_matches {
+                return true;
+            }
+        }
+        let digits = match query.strip_prefix('#') {
+            Some(digits) if opts.hash_prefixed => digits,
+            Some(_) => return false,
+            None if opts.numeric => query,
+            None => return false,
+        };
+        digits.bytes().all(|b| b.is_ascii_digit())
+            && digits.parse::<u64>().is_ok_and(|value| value == self.value)
+    }
+
+    /// Returns true if `name` equals [`KnownValue::name`], optionally
+    /// ignoring ASCII case.
+    ///
+    /// Unlike [`KnownValue::matches`], a named value never matches its
+    /// codepoint; a value without an assigned name matches only its codepoint
+    /// in decimal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert!(known_values::IS_A.eq_name("isA", false));
+    /// assert!(!known_values::IS_A.eq_name("ISA", false));
+    /// assert!(known_values::IS_A.eq_name("ISA", true));
+    /// assert!(!known_values::IS_A.eq_name("1", true));
+    /// assert!(KnownValue::new(42).eq_name("42", false));
+    /// ```
+    pub fn eq_name(&self, name: &str, ignore_case: bool) -> bool {
+        match self.assigned_name() {
+            Some(assigned) if ignore_case => {
+                assigned.eq_ignore_ascii_case(name)
+            }
+            Some(assigned) => assigned == name,
+            None => self.value.to_string() == name,
+        }
+    }
+
+    /// Returns the assigned name if it was provided at compile time.
+    pub(crate) const fn static_name(&self) -> Option<&'static str> {
+        match &self.assigned_name {
+            Some(KnownValueName::Static(name)) => Some(name),
+            _ => None,
+        }
+    }
+
     /// Returns a human-readable name for the KnownValue.
     ///
     /// If the KnownValue has an assigned name, that name is returned.
@@ -182,13 +767,331 @@ impl KnownValue {
         match &self.assigned_name {
             Some(KnownValueName::Static(name)) => name.to_string(),
             Some(KnownValueName::Dynamic(name)) => name.clone(),
+            Some(KnownValueName::Interned(name)) => name.to_string(),
             None => self.value.to_string(),
         }
     }
+
+    /// Returns the KnownValue with the next higher codepoint, or None if this
+    /// is `u64::MAX`.
+    ///
+    /// The returned KnownValue has no assigned name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(known_values::IS_A.successor().unwrap().value(), 2);
+    /// assert_eq!(
+    ///     known_values::IS_A.successor().unwrap().assigned_name(),
+    ///     None
+    /// );
+    /// assert_eq!(KnownValue::new(0).successor().unwrap().value(), 1);
+    /// assert!(KnownValue::new(u64::MAX).successor().is_none());
+    /// ```
+    pub fn successor(&self) -> Option<KnownValue> {
+        self.value.checked_add(1).map(KnownValue::new)
+    }
+
+    /// Returns the KnownValue with the next lower codepoint, or None if this
+    /// is 0.
+    ///
+    /// The returned KnownValue has no assigned name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// assert_eq!(known_values::NOTE.predecessor().unwrap().value(), 3);
+    /// assert_eq!(
+    ///     KnownValue::new(u64::MAX).predecessor().unwrap().value(),
+    ///     u64::MAX - 1
+    /// );
+    /// assert!(KnownValue::new(0).predecessor().is_none());
+    /// ```
+    pub fn predecessor(&self) -> Option<KnownValue> {
+        self.value.checked_sub(1).map(KnownValue::new)
+    }
+
+    /// Returns a copy of this KnownValue with the given entry type (e.g.,
+    /// "property", "class", or "value").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value = KnownValue::new_with_name(1000u64, "myValue".to_string())
+    ///     .with_entry_type("property");
+    /// assert_eq!(known_value.entry_type(), Some("property"));
+    /// ```
+    pub fn with_entry_type(mut self, entry_type: impl Into<String>) -> Self {
+        self.metadata_mut().entry_type = Some(entry_type.into());
+        self
+    }
+
+    /// Returns a copy of this KnownValue with the given URI reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value = KnownValue::new_with_name(1000u64, "myValue".to_string())
+    ///     .with_uri("https://example.com/vocab#myValue");
+    /// assert_eq!(known_value.uri(), Some("https://example.com/vocab#myValue"));
+    /// ```
+    pub fn with_uri(mut self, uri: impl Into<String>) -> Self {
+        self.metadata_mut().uri = Some(uri.into());
+        self
+    }
+
+    /// Returns a copy of this KnownValue with the given human-readable
+    /// description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value = KnownValue::new_with_name(1000u64, "myValue".to_string())
+    ///     .with_description("A custom known value");
+    /// assert_eq!(known_value.description(), Some("A custom known value"));
+    /// ```
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.metadata_mut().description = Some(description.into());
+        self
+    }
+
+    /// Returns a copy of this KnownValue marked as deprecated or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value =
+    ///     KnownValue::new_with_name(1000u64, "oldValue".to_string())
+    ///         .with_deprecated(true);
+    /// assert!(known_value.is_deprecated());
+    /// ```
+    pub fn with_deprecated(mut self, deprecated: bool) -> Self {
+        self.metadata_mut().deprecated = deprecated;
+        self
+    }
+
+    /// Returns a copy of this KnownValue with a display name for the given
+    /// language tag (e.g., "en" or "en-GB").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value = KnownValue::new_with_name(1000u64, "color".to_string())
+    ///     .with_localized_name("en-GB", "colour");
+    /// assert_eq!(known_value.localized_name("en-GB"), Some("colour"));
+    /// ```
+    pub fn with_localized_name(
+        mut self,
+        lang: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.metadata_mut()
+            .localized_names
+            .insert(lang.into(), name.into());
+        self
+    }
+
+    /// Returns the display name for a language tag, if one exists.
+    ///
+    /// If there is no name for the exact tag, subtags are removed from the end
+    /// until a match is found, so "en-US" falls back to "en". The canonical
+    /// name is not consulted; see [`KnownValuesStore::name_localized`].
+    ///
+    /// [`KnownValuesStore::name_localized`]: crate::KnownValuesStore::name_localized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value = KnownValue::new_with_name(1000u64, "color".to_string())
+    ///     .with_localized_name("en", "color")
+    ///     .with_localized_name("en-GB", "colour");
+    /// assert_eq!(known_value.localized_name("en-GB"), Some("colour"));
+    /// assert_eq!(known_value.localized_name("en-US"), Some("color"));
+    /// assert_eq!(known_value.localized_name("fr"), None);
+    /// ```
+    pub fn localized_name(&self, lang: &str) -> Option<&str> {
+        let names = &self.metadata.as_ref()?.localized_names;
+        let mut lang = lang;
+        loop {
+            if let Some(name) = names.get(lang) {
+                return Some(name);
+            }
+            lang = &lang[..lang.rfind('-')?];
+        }
+    }
+
+    /// Returns the entry type of the KnownValue, if one exists.
+    ///
+    /// Builtin registry constants carry no metadata, so this returns `None`
+    /// for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(known_values::IS_A.entry_type(), None);
+    /// ```
+    pub fn entry_type(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|m| m.entry_type.as_deref())
+    }
+
+    /// Returns the URI reference of the KnownValue, if one exists.
+    ///
+    /// Builtin registry constants carry no metadata, so this returns `None`
+    /// for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(known_values::IS_A.uri(), None);
+    /// ```
+    pub fn uri(&self) -> Option<&str> {
+        self.metadata.as_ref().and_then(|m| m.uri.as_deref())
+    }
+
+    /// Returns the human-readable description of the KnownValue, if one
+    /// exists.
+    ///
+    /// Builtin registry constants carry no metadata, so this returns `None`
+    /// for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(known_values::IS_A.description(), None);
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.description.as_deref())
+    }
+
+    /// Returns true if the KnownValue has been marked as deprecated in its
+    /// registry.
+    ///
+    /// Builtin registry constants are never deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(!known_values::IS_A.is_deprecated());
+    /// ```
+    pub fn is_deprecated(&self) -> bool {
+        self.metadata.as_ref().is_some_and(|m| m.deprecated)
+    }
+
+    /// Converts the KnownValue into a registry entry, carrying over its
+    /// metadata.
+    ///
+    /// A KnownValue without an assigned name uses its numeric value as the
+    /// entry name, matching [`KnownValue::name`].
+    ///
+    /// This method is only available when the `directory-loading` feature is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValue;
+    ///
+    /// let known_value = KnownValue::new_with_name(1000u64, "myValue".to_string())
+    ///     .with_entry_type("property")
+    ///     .with_localized_name("fr", "maValeur");
+    ///
+    /// let entry = known_value.to_registry_entry();
+    /// assert_eq!(entry.codepoint, 1000);
+    /// assert_eq!(entry.name, "myValue");
+    /// assert_eq!(entry.entry_type.as_deref(), Some("property"));
+    /// assert_eq!(entry.names_by_lang["fr"], "maValeur");
+    ///
+    /// assert_eq!(KnownValue::new(42).to_registry_entry().name, "42");
+    /// ```
+    #[cfg(feature = "directory-loading")]
+    pub fn to_registry_entry(&self) -> crate::RegistryEntry {
+        let metadata = self.metadata.as_deref();
+        crate::RegistryEntry {
+            codepoint: self.value,
+            name: self.name(),
+            entry_type: self.entry_type().map(str::to_string),
+            uri: self.uri().map(str::to_string),
+            description: self.description().map(str::to_string),
+            deprecated: self.is_deprecated(),
+            names_by_lang: metadata
+                .map(|m| {
+                    m.localized_names
+                        .iter()
+                        .map(|(lang, name)| (lang.clone(), name.clone()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn metadata_mut(&mut self) -> &mut KnownValueMetadata {
+        self.metadata.get_or_insert_with(Default::default)
+    }
+}
+
+/// Error returned by [`KnownValue::try_new_bounded`] when a codepoint exceeds
+/// the allowed maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodepointOutOfRange {
+    /// The rejected codepoint.
+    pub value: u64,
+    /// The maximum allowed codepoint.
+    pub max: u64,
+}
+
+impl Display for CodepointOutOfRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "codepoint {} exceeds the maximum allowed codepoint {}",
+            self.value, self.max
+        )
+    }
+}
+
+impl std::error::Error for CodepointOutOfRange {}
+
+/// Options controlling how [`KnownValue::matches`] compares a query.
+///
+/// The default matches names case-sensitively and accepts a bare decimal
+/// codepoint, but not a `#`-prefixed one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchOptions {
+    /// Whether names must match exactly, or ignoring ASCII case.
+    pub case_sensitive: bool,
+    /// Whether a bare decimal number matches the codepoint, e.g. `"1"`.
+    pub numeric: bool,
+    /// Whether a `#`-prefixed decimal number matches the codepoint, e.g.
+    /// `"#1"`.
+    pub hash_prefixed: bool,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        Self { case_sensitive: true, numeric: true, hash_prefixed: false }
+    }
 }
 
 /// Equality for KnownValue is based solely on the numeric value, ignoring the
-/// name.
+/// name. Use [`KnownValue::is_exactly`] to also compare names.
 impl PartialEq for KnownValue {
     fn eq(&self, other: &Self) -> bool { self.value == other.value }
 }
@@ -197,6 +1100,23 @@ impl PartialEq for KnownValue {
 /// can be compared for equality.
 impl Eq for KnownValue {}
 
+/// Compares a KnownValue with a registry entry by codepoint and assigned name.
+///
+/// Metadata is ignored. A KnownValue without an assigned name never equals
+/// an entry, since every entry has a name.
+#[cfg(feature = "directory-loading")]
+impl PartialEq<crate::RegistryEntry> for KnownValue {
+    fn eq(&self, entry: &crate::RegistryEntry) -> bool {
+        self.value == entry.codepoint
+            && self.assigned_name() == Some(entry.name.as_str())
+    }
+}
+
+#[cfg(feature = "directory-loading")]
+impl PartialEq<KnownValue> for crate::RegistryEntry {
+    fn eq(&self, known_value: &KnownValue) -> bool { known_value == self }
+}
+
 /// Hash implementation for KnownValue that considers only the numeric value.
 impl std::hash::Hash for KnownValue {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -208,16 +1128,58 @@ impl std::hash::Hash for KnownValue {
 ///
 /// If a name is assigned, the name is displayed. Otherwise, the numeric value
 /// is displayed.
+///
+/// The alternate form (`{:#}`) appends the codepoint in parentheses to an
+/// assigned name, e.g. `isA(1)`. A value without an assigned name is shown as
+/// its bare codepoint in both forms, since repeating it adds nothing.
+///
+/// An unresolved placeholder (see [`KnownValue::unresolved`]) is prefixed
+/// with `?`, e.g. `?isA`, so that logs do not suggest its codepoint is known.
+/// Its sentinel codepoint is never shown alongside the name.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::KnownValue;
+///
+/// assert_eq!(format!("{}", known_values::IS_A), "isA");
+/// assert_eq!(format!("{:#}", known_values::IS_A), "isA(1)");
+/// assert_eq!(format!("{:#}", KnownValue::new(999)), "999");
+/// assert_eq!(format!("{:#}", KnownValue::unresolved("isA")), "?isA");
+/// ```
 impl Display for KnownValue {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.is_resolved() {
+            f.write_str("?")?;
+        } else if f.alternate()
+            && let Some(name) = self.assigned_name()
+        {
+            return write!(f, "{}({})", name, self.value);
+        }
         match &self.assigned_name {
             Some(KnownValueName::Static(name)) => write!(f, "{}", name),
             Some(KnownValueName::Dynamic(name)) => write!(f, "{}", name),
+            Some(KnownValueName::Interned(name)) => write!(f, "{}", name),
             None => write!(f, "{}", self.value),
         }
     }
 }
 
+/// Formats the numeric value of the KnownValue in lowercase hexadecimal,
+/// honoring width, padding, and the `#` flag.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(format!("{:x}", known_values::SELF), "2c2");
+/// assert_eq!(format!("{:#06x}", known_values::NOTE), "0x0004");
+/// ```
+impl LowerHex for KnownValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        LowerHex::fmt(&self.value, f)
+    }
+}
+
 /// Provides a cryptographic digest for a KnownValue.
 impl DigestProvider for KnownValue {
     fn digest(&self) -> Digest {
@@ -225,7 +1187,8 @@ impl DigestProvider for KnownValue {
     }
 }
 
-/// Specifies the CBOR tag used for KnownValue.
+/// Specifies the CBOR tag used for KnownValue: the standard Known Value tag,
+/// 40000.
 impl CBORTagged for KnownValue {
     fn cbor_tags() -> Vec<Tag> { tags_for_values(&[tags::TAG_KNOWN_VALUE]) }
 }
@@ -271,3 +1234,470 @@ impl From<i32> for KnownValue {
 impl From<usize> for KnownValue {
     fn from(value: usize) -> Self { KnownValue::new(value as u64) }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNNAMED: KnownValue = KnownValue::new(1234);
+    const UNNAMED_TABLE: [KnownValue; 2] =
+        [KnownValue::new(1), KnownValue::new(u64::MAX)];
+
+    #[test]
+    fn test_new_is_const() {
+        assert_eq!(UNNAMED.value(), 1234);
+        assert_eq!(UNNAMED.assigned_name(), None);
+        assert_eq!(UNNAMED_TABLE[1].value(), u64::MAX);
+    }
+
+    #[test]
+    fn test_matches_option_combinations() {
+        let named = KnownValue::new_with_name(12u64, "myValue".to_string());
+        let unnamed = KnownValue::new(12);
+        let queries = ["myValue", "MYVALUE", "12", "#12", "13", "#13", "#"];
+
+        for case_sensitive in [true, false] {
+            for numeric in [true, false] {
+                for hash_prefixed in [true, false] {
+                    let opts =
+                        MatchOptions { case_sensitive, numeric, hash_prefixed };
+                    for query in queries {
+                        let by_name = match query {
+                            "myValue" => true,
+                            "MYVALUE" => !case_sensitive,
+                            _ => false,
+                        };
+                        let by_number = match query {
+                            "12" => numeric,
+                            "#12" => hash_prefixed,
+                            _ => false,
+                        };
+                        assert_eq!(
+                            named.matches(query, opts),
+                            by_name || by_number,
+                            "{:?} {:?}",
+                            query,
+                            opts
+                        );
+                        assert_eq!(
+                            unnamed.matches(query, opts),
+                            by_number,
+                            "{:?} {:?}",
+                            query,
+                            opts
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_new_bounded() {
+        assert_eq!(KnownValue::try_new_bounded(99, 100).unwrap().value(), 99);
+        assert_eq!(KnownValue::try_new_bounded(100, 100).unwrap().value(), 100);
+        assert_eq!(
+            KnownValue::try_new_bounded(101, 100),
+            Err(CodepointOutOfRange { value: 101, max: 100 })
+        );
+        assert!(KnownValue::try_new_bounded(0, 0).is_ok());
+        assert!(KnownValue::try_new_bounded(u64::MAX, u64::MAX).is_ok());
+        assert_eq!(
+            KnownValue::try_new_bounded(1, 0).unwrap_err().to_string(),
+            "codepoint 1 exceeds the maximum allowed codepoint 0"
+        );
+    }
+
+    #[cfg(feature = "directory-loading")]
+    #[test]
+    fn test_registry_entry_round_trip() {
+        let original = KnownValue::new_with_name(1000u64, "color".to_string())
+            .with_entry_type("property")
+            .with_uri("https://example.com/vocab#color")
+            .with_description("A color")
+            .with_deprecated(true)
+            .with_localized_name("en-GB", "colour");
+
+        let round_tripped = KnownValue::from(original.to_registry_entry());
+        assert_eq!(round_tripped.value(), 1000);
+        assert_eq!(round_tripped.assigned_name(), Some("color"));
+        assert_eq!(round_tripped.entry_type(), Some("property"));
+        assert_eq!(
+            round_tripped.uri(),
+            Some("https://example.com/vocab#color")
+        );
+        assert_eq!(round_tripped.description(), Some("A color"));
+        assert!(round_tripped.is_deprecated());
+        assert_eq!(round_tripped.localized_name("en-GB"), Some("colour"));
+        assert_eq!(
+            round_tripped.to_registry_entry(),
+            original.to_registry_entry()
+        );
+
+        let bare = crate::IS_A.to_registry_entry();
+        assert_eq!(bare.name, "isA");
+        assert_eq!(bare.entry_type, None);
+        assert!(!bare.deprecated);
+        assert!(bare.names_by_lang.is_empty());
+    }
+
+    #[test]
+    fn test_name_bytes() {
+        let dynamic = KnownValue::new_with_name(1000u64, "café".to_string());
+        assert_eq!(dynamic.name_bytes(), Some("café".as_bytes()));
+        assert_eq!(dynamic.name_bytes().unwrap().len(), 5);
+        assert_eq!(
+            dynamic.name_bytes().unwrap().as_ptr(),
+            dynamic.assigned_name().unwrap().as_ptr()
+        );
+
+        assert_eq!(crate::NOTE.name_bytes(), Some(&b"note"[..]));
+        assert_eq!(KnownValue::new(42).name_bytes(), None);
+    }
+
+    #[test]
+    fn test_equality_semantics() {
+        let is_a = KnownValue::new_with_name(1u64, "isA".to_string());
+        let shouty = KnownValue::new_with_name(1u64, "IS_A".to_string());
+        let bare = KnownValue::new(1);
+
+        assert_eq!(is_a, shouty);
+        assert!(is_a.is_equivalent_ignoring_name(&shouty));
+        assert!(!is_a.is_exactly(&shouty));
+
+        assert!(is_a.is_equivalent_ignoring_name(&bare));
+        assert!(!is_a.is_exactly(&bare));
+        assert!(bare.is_exactly(&KnownValue::new(1)));
+
+        // Static and dynamic names compare by content
+        assert!(is_a.is_exactly(&crate::IS_A));
+        assert!(!is_a.is_exactly(&crate::ID));
+    }
+
+    #[test]
+    fn test_codepoint_byte_width_boundaries() {
+        assert_eq!(KnownValue::new(0).codepoint_byte_width(), 1);
+        for width in 1..8u8 {
+            let max = (1u64 << (8 * width as u32)) - 1;
+            assert_eq!(KnownValue::new(max).codepoint_byte_width(), width);
+            assert_eq!(
+                KnownValue::new(max + 1).codepoint_byte_width(),
+                width + 1
+            );
+        }
+        assert_eq!(KnownValue::new(255).codepoint_byte_width(), 1);
+        assert_eq!(KnownValue::new(256).codepoint_byte_width(), 2);
+        assert_eq!(KnownValue::new(65_535).codepoint_byte_width(), 2);
+        assert_eq!(KnownValue::new(65_536).codepoint_byte_width(), 3);
+        assert_eq!(KnownValue::new(u64::MAX).codepoint_byte_width(), 8);
+    }
+
+    #[test]
+    fn test_interned_names_share_allocation() {
+        let a = KnownValue::interned(1u64, "internedName");
+        let b = KnownValue::interned(2u64, "internedName");
+        let a_name = a.assigned_name().unwrap();
+        let b_name = b.assigned_name().unwrap();
+        assert_eq!(a_name, "internedName");
+        assert_eq!(a_name.as_ptr(), b_name.as_ptr());
+
+        let other = KnownValue::interned(1u64, "otherInternedName");
+        assert_ne!(other.assigned_name().unwrap().as_ptr(), a_name.as_ptr());
+
+        // Interned values behave like any other named value
+        let dynamic =
+            KnownValue::new_with_name(1u64, "internedName".to_string());
+        assert!(a.is_exactly(&dynamic));
+        assert_eq!(a.to_string(), "internedName");
+        assert_eq!(
+            a.clone().assigned_name().unwrap().as_ptr(),
+            a_name.as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let start = KnownValue::new_with_name(1000u64, "start".to_string());
+        let next = start.checked_add(5).unwrap();
+        assert_eq!(next.value(), 1005);
+        assert_eq!(next.assigned_name(), None);
+        assert!(
+            start
+                .checked_add(0)
+                .unwrap()
+                .is_exactly(&KnownValue::new(1000))
+        );
+
+        let near_max = KnownValue::new(u64::MAX - 1);
+        assert_eq!(near_max.checked_add(1).unwrap().value(), u64::MAX);
+        assert_eq!(near_max.checked_add(2), None);
+        assert_eq!(KnownValue::new(u64::MAX).checked_add(1), None);
+        assert_eq!(KnownValue::new(1).checked_add(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_from_cbor_data() {
+        let data = crate::SELF.to_cbor_data();
+        let decoded = KnownValue::from_cbor_data(&data).unwrap();
+        assert_eq!(decoded.value(), 706);
+        assert_eq!(decoded.assigned_name(), None);
+
+        let large = KnownValue::new(u64::MAX - 1).to_cbor_data();
+        assert_eq!(
+            KnownValue::from_cbor_data(&large).unwrap().value(),
+            u64::MAX - 1
+        );
+
+        let wrong_tag = crate::SELF.to_cbor_with_tag(99).to_cbor_data();
+        assert!(KnownValue::from_cbor_data(&wrong_tag).is_err());
+
+        let untagged = CBOR::from(706u64).to_cbor_data();
+        assert!(KnownValue::from_cbor_data(&untagged).is_err());
+
+        let mut trailing = data.clone();
+        trailing.push(0x00);
+        assert!(KnownValue::from_cbor_data(&trailing).is_err());
+
+        assert!(KnownValue::from_cbor_data(&data[..data.len() - 1]).is_err());
+        assert!(KnownValue::from_cbor_data(&[]).is_err());
+    }
+
+    #[test]
+    fn test_hex_formatting() {
+        let zero = KnownValue::new(0);
+        let large =
+            KnownValue::new_with_name(0xdead_beefu64, "big".to_string());
+        assert_eq!(zero.value_hex(), "0x0");
+        assert_eq!(large.value_hex(), "0xdeadbeef");
+        assert_eq!(KnownValue::new(u64::MAX).value_hex(), "0xffffffffffffffff");
+
+        assert_eq!(format!("{:x}", zero), "0");
+        assert_eq!(format!("{:x}", large), "deadbeef");
+        assert_eq!(format!("{:#x}", large), "0xdeadbeef");
+        assert_eq!(format!("{:#06x}", zero), "0x0000");
+        assert_eq!(format!("{:#06x}", crate::SELF), "0x02c2");
+        assert_eq!(format!("{:#06x}", large), "0xdeadbeef");
+        assert_eq!(format!("{:04x}", crate::IS_A), "0001");
+    }
+
+    #[test]
+    fn test_eq_name() {
+        let note = crate::NOTE;
+        assert!(note.eq_name("note", false));
+        assert!(note.eq_name("note", true));
+        assert!(!note.eq_name("Note", false));
+        assert!(note.eq_name("NOTE", true));
+        assert!(!note.eq_name("notes", true));
+        assert!(!note.eq_name("4", false));
+
+        let dynamic = KnownValue::new_with_name(1000u64, "myValue".to_string());
+        assert!(dynamic.eq_name("myValue", false));
+        assert!(dynamic.eq_name("MYVALUE", true));
+
+        let bare = KnownValue::new(42);
+        assert!(bare.eq_name("42", false));
+        assert!(bare.eq_name("42", true));
+        for other in ["042", "#42", "+42", "", "4"] {
+            assert!(!bare.eq_name(other, true), "{:?}", other);
+        }
+    }
+
+    #[test]
+    fn test_display_alternate_form() {
+        assert_eq!(format!("{:#}", crate::IS_A), "isA(1)");
+        assert_eq!(format!("{}", crate::IS_A), "isA");
+
+        let dynamic = KnownValue::new_with_name(1000u64, "myValue".to_string());
+        assert_eq!(format!("{:#}", dynamic), "myValue(1000)");
+        assert_eq!(
+            format!("{:#}", KnownValue::interned(5u64, "interned")),
+            "interned(5)"
+        );
+
+        let bare = KnownValue::new(999);
+        assert_eq!(format!("{:#}", bare), "999");
+        assert_eq!(format!("{}", bare), "999");
+    }
+
+    #[test]
+    fn test_display_marks_unresolved_placeholders() {
+        let placeholder = KnownValue::unresolved("isA");
+        assert_eq!(placeholder.to_string(), "?isA");
+        assert_eq!(format!("{:#}", placeholder), "?isA");
+        assert_eq!(placeholder.name(), "isA");
+
+        assert_eq!(crate::IS_A.to_string(), "isA");
+        assert_eq!(KnownValue::new(42).to_string(), "42");
+        assert_eq!(
+            KnownValue::new(KnownValue::UNRESOLVED).to_string(),
+            KnownValue::UNRESOLVED.to_string()
+        );
+    }
+
+    #[test]
+    fn test_max_codepoint_is_resolved() {
+        let max = KnownValue::new(u64::MAX);
+        assert!(max.is_resolved());
+        assert_eq!(max.to_string(), "18446744073709551615");
+
+        let decoded = KnownValue::from_cbor_data(&max.to_cbor_data()).unwrap();
+        assert_eq!(decoded.value(), u64::MAX);
+        assert!(decoded.is_resolved());
+        assert_eq!(decoded.to_string(), "18446744073709551615");
+
+        let store = crate::KnownValuesStore::with_builtins();
+        let parsed = store.known_value_named_or_new("18446744073709551615");
+        assert_eq!(parsed.value(), u64::MAX);
+        assert!(parsed.is_resolved());
+
+        // A placeholder is still unresolved, and not mistaken for the value
+        let placeholder = KnownValue::unresolved("isA");
+        assert_eq!(placeholder.value(), u64::MAX);
+        assert!(!placeholder.is_resolved());
+        assert!(!placeholder.is_exactly(&KnownValue::new_with_name(
+            u64::MAX,
+            "isA".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_matches_rejects_malformed_numbers() {
+        let opts = MatchOptions { hash_prefixed: true, ..Default::default() };
+        let known_value = KnownValue::new(7);
+        for query in ["", "+7", "07x", "# 7", "##7", "-7", " 7"] {
+            assert!(!known_value.matches(query, opts), "{:?}", query);
+        }
+    }
+
+    #[test]
+    fn test_category_name() {
+        assert_eq!(crate::UNIT.category_name(), "General");
+        assert_eq!(crate::CONFORMS_TO.category_name(), "Attachments");
+        assert_eq!(KnownValue::new(69).category_name(), "XID Documents");
+        assert_eq!(crate::PRIVILEGE_REVOKE.category_name(), "XID Privileges");
+        assert_eq!(crate::CONTENT.category_name(), "Expressions");
+        assert_eq!(crate::MASTER_KEY_TYPE.category_name(), "Cryptography");
+        assert_eq!(
+            crate::BITCOIN_VALUE.category_name(),
+            "Cryptocurrency Assets"
+        );
+        assert_eq!(
+            crate::TEST_NET_VALUE.category_name(),
+            "Cryptocurrency Networks"
+        );
+        assert_eq!(crate::PSBT_TYPE.category_name(), "Bitcoin");
+        assert_eq!(crate::GRAPH.category_name(), "Graphs");
+        assert_eq!(crate::SELF.category_name(), "Graphs");
+        assert_eq!(KnownValue::new(800).category_name(), "Unassigned");
+        assert_eq!(KnownValue::new(u64::MAX).category_name(), "Unassigned");
+
+        // Every registry group maps to its own category, so a group added
+        // without a matching block fails here
+        let groups: [(&[KnownValue], Category); 10] = [
+            (crate::GENERAL, Category::General),
+            (crate::ATTACHMENTS, Category::Attachments),
+            (crate::XID_DOCUMENTS, Category::XidDocuments),
+            (crate::XID_PRIVILEGES, Category::XidPrivileges),
+            (crate::EXPRESSIONS, Category::Expressions),
+            (crate::CRYPTOGRAPHY, Category::Cryptography),
+            (crate::CRYPTOCURRENCY_ASSETS, Category::CryptocurrencyAssets),
+            (
+                crate::CRYPTOCURRENCY_NETWORKS,
+                Category::CryptocurrencyNetworks,
+            ),
+            (crate::BITCOIN, Category::Bitcoin),
+            (crate::GRAPHS, Category::Graphs),
+        ];
+        for (group, category) in groups {
+            for value in group {
+                assert_eq!(value.category(), category, "{:?}", value);
+                assert_eq!(value.category_name(), category.name());
+            }
+        }
+        let grouped: usize = groups.iter().map(|(group, _)| group.len()).sum();
+        assert_eq!(grouped, crate::known_values_registry::BUILTINS.len());
+
+        // The category ranges tile the whole codepoint space in order
+        let mut next = 0;
+        for category in Category::ALL {
+            let range = category.range();
+            assert_eq!(*range.start(), next, "{}", category);
+            assert_eq!(Category::of(*range.start()), category);
+            assert_eq!(Category::of(*range.end()), category);
+            next = range.end().wrapping_add(1);
+        }
+        assert_eq!(next, 0);
+        assert_eq!(Category::XidPrivileges.to_string(), "XID Privileges");
+    }
+
+    #[test]
+    fn test_unassigned_sentinel() {
+        const SENTINEL: KnownValue = KnownValue::UNASSIGNED;
+        assert!(SENTINEL.is_unassigned());
+        assert_eq!(SENTINEL.value(), 0);
+        assert_eq!(SENTINEL.assigned_name(), None);
+        assert!(SENTINEL.is_resolved());
+        assert_eq!(SENTINEL, crate::UNIT);
+        assert!(!SENTINEL.is_exactly(&crate::UNIT));
+        assert!(!KnownValue::new(1).is_unassigned());
+        assert!(!KnownValue::unresolved("x").is_unassigned());
+    }
+
+    #[test]
+    fn test_map_name() {
+        let described =
+            KnownValue::new_with_name(1000u64, "custom".to_string())
+                .with_description("A custom value");
+
+        let upper = described.clone().map_name(|name| {
+            assert_eq!(name, Some("custom"));
+            name.map(str::to_uppercase)
+        });
+        assert_eq!(upper.value(), 1000);
+        assert_eq!(upper.assigned_name(), Some("CUSTOM"));
+        assert_eq!(upper.description(), Some("A custom value"));
+
+        let cleared = described.map_name(|_| None);
+        assert_eq!(cleared.value(), 1000);
+        assert_eq!(cleared.assigned_name(), None);
+        assert_eq!(cleared.description(), Some("A custom value"));
+
+        let named = KnownValue::new(42).map_name(|name| {
+            assert_eq!(name, None);
+            Some("answer".to_string())
+        });
+        assert!(named.is_exactly(&KnownValue::new_with_name(
+            42u64,
+            "answer".to_string()
+        )));
+    }
+
+    #[cfg(feature = "directory-loading")]
+    #[test]
+    fn test_equality_with_registry_entry() {
+        let entry: crate::RegistryEntry = serde_json::from_str(
+            r#"{"codepoint": 1000, "name": "color", "type": "property"}"#,
+        )
+        .unwrap();
+
+        let matching = KnownValue::new_with_name(1000u64, "color".to_string());
+        assert_eq!(matching, entry);
+        assert_eq!(entry, matching);
+        assert_eq!(matching.clone().with_description("ignored"), entry);
+
+        assert_ne!(
+            KnownValue::new_with_name(1001u64, "color".to_string()),
+            entry
+        );
+        assert_ne!(
+            KnownValue::new_with_name(1000u64, "hue".to_string()),
+            entry
+        );
+
+        // A bare value never matches, even against its numeric fallback name.
+        assert_ne!(KnownValue::new(1000), entry);
+        let bare = KnownValue::new(42);
+        assert_ne!(bare, bare.to_registry_entry());
+        assert_eq!(crate::UNIT, crate::UNIT.to_registry_entry());
+    }
+}
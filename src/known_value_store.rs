@@ -1,8 +1,14 @@
-use std::collections::HashMap;
 #[cfg(feature = "directory-loading")]
 use std::path::Path;
+use std::{
+    cmp::Ordering, collections::HashMap, fmt, ops::RangeInclusive, sync::Arc,
+};
 
-use super::known_value::KnownValue;
+use super::{
+    category::Category, known_value::KnownValue,
+    known_values_registry::BUILTINS, overlay_store::OverlayStore,
+    store_diff::StoreDiff,
+};
 
 /// A store that maps between Known Values and their assigned names.
 ///
@@ -52,10 +58,10 @@ use super::known_value::KnownValue;
 ///     100
 /// );
 /// ```
-#[derive(Clone, Debug)]
 pub struct KnownValuesStore {
     known_values_by_raw_value: HashMap<u64, KnownValue>,
-    known_values_by_assigned_name: HashMap<String, KnownValue>,
+    known_values_by_assigned_name: HashMap<Arc<str>, KnownValue>,
+    known_values_by_uri: HashMap<String, u64>,
 }
 
 impl KnownValuesStore {
@@ -85,21 +91,50 @@ impl KnownValuesStore {
     where
         T: IntoIterator<Item = KnownValue>,
     {
-        let mut known_values_by_raw_value = HashMap::new();
-        let mut known_values_by_assigned_name = HashMap::new();
+        let known_values = known_values.into_iter();
+        // Pre-size both indexes so large registries are not rehashed as they
+        // grow; the lower bound is exact for slices, vectors, and arrays.
+        let (capacity, _) = known_values.size_hint();
+        let mut known_values_by_raw_value = HashMap::with_capacity(capacity);
+        let mut known_values_by_assigned_name =
+            HashMap::with_capacity(capacity);
+        let mut known_values_by_uri = HashMap::new();
         for known_value in known_values {
             Self::_insert(
                 known_value,
                 &mut known_values_by_raw_value,
                 &mut known_values_by_assigned_name,
+                &mut known_values_by_uri,
             );
         }
         Self {
             known_values_by_raw_value,
             known_values_by_assigned_name,
+            known_values_by_uri,
         }
     }
 
+    /// Creates a new KnownValuesStore containing every Known Value defined in
+    /// the registry.
+    ///
+    /// The store is independent of the global `KNOWN_VALUES` registry: it
+    /// does not include values loaded from directories, and changes to it
+    /// are not visible globally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let mut store = KnownValuesStore::with_builtins();
+    /// assert_eq!(store.known_value_named("isA").unwrap().value(), 1);
+    /// assert_eq!(store.known_value_named("note").unwrap().value(), 4);
+    ///
+    /// store.insert(KnownValue::new_with_name(1000u64, "custom".to_string()));
+    /// assert_eq!(store.codepoint_of("custom"), Some(1000));
+    /// ```
+    pub fn with_builtins() -> Self { Self::new(BUILTINS.iter().cloned()) }
+
     /// Inserts a KnownValue into the store.
     ///
     /// If the KnownValue has an assigned name, it will be indexed by both its
@@ -120,9 +155,105 @@ impl KnownValuesStore {
             known_value,
             &mut self.known_values_by_raw_value,
             &mut self.known_values_by_assigned_name,
+            &mut self.known_values_by_uri,
         );
     }
 
+    /// Inserts a clone of each value in `known_values`, such as one of the
+    /// builtin groups.
+    ///
+    /// Values are inserted in order, with the same replacement rules as
+    /// [`KnownValuesStore::insert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let mut store = KnownValuesStore::default();
+    /// store.extend_from_slice(known_values::ATTACHMENTS);
+    /// assert_eq!(store.codepoint_of("vendor"), Some(51));
+    /// ```
+    pub fn extend_from_slice(&mut self, known_values: &[KnownValue]) {
+        for known_value in known_values {
+            self.insert(known_value.clone());
+        }
+    }
+
+    /// Replaces the entire contents of the store with `known_values`.
+    ///
+    /// This is equivalent to `*self = KnownValuesStore::new(known_values)`,
+    /// but reuses the store's existing allocations. Because it completes in a
+    /// single call, a store guarded by a lock is never observed partially
+    /// populated, mirroring the global registry's reload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let mut store =
+    ///     KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// store.replace_all([known_values::SIGNED]);
+    ///
+    /// assert_eq!(store.codepoint_of("signed"), Some(3));
+    /// assert!(store.known_value_named("isA").is_none());
+    /// ```
+    pub fn replace_all<T>(&mut self, known_values: T)
+    where
+        T: IntoIterator<Item = KnownValue>,
+    {
+        self.known_values_by_raw_value.clear();
+        self.known_values_by_assigned_name.clear();
+        self.known_values_by_uri.clear();
+        for known_value in known_values {
+            self.insert(known_value);
+        }
+    }
+
+    /// Inserts a KnownValue only if its codepoint is not already present.
+    ///
+    /// Returns true if the value was inserted. An existing value with the same
+    /// codepoint, including its assigned name, is left untouched. This is the
+    /// single-value analog of `MergeStrategy::FirstWins`.
+    ///
+    /// The value is also skipped if its name is already assigned to another
+    /// codepoint, since [`KnownValuesStore::insert`] would take the name
+    /// away from the existing value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let mut store = KnownValuesStore::new([known_values::IS_A]);
+    ///
+    /// assert!(store.insert_if_absent(known_values::NOTE));
+    /// assert_eq!(store.known_value_named("note").unwrap().value(), 4);
+    ///
+    /// assert!(
+    ///     !store.insert_if_absent(KnownValue::new_with_name(
+    ///         1u64,
+    ///         "type".to_string()
+    ///     ))
+    /// );
+    /// assert_eq!(store.assigned_name(&known_values::IS_A), Some("isA"));
+    /// assert!(store.known_value_named("type").is_none());
+    /// ```
+    pub fn insert_if_absent(&mut self, known_value: KnownValue) -> bool {
+        if self
+            .known_values_by_raw_value
+            .contains_key(&known_value.value())
+            || known_value.assigned_name().is_some_and(|name| {
+                self.known_values_by_assigned_name.contains_key(name)
+            })
+        {
+            return false;
+        }
+        self.insert(known_value);
+        true
+    }
+
     /// Returns the assigned name for a KnownValue, if present in the store.
     ///
     /// # Examples
@@ -163,6 +294,58 @@ impl KnownValuesStore {
             .unwrap_or_else(|| known_value.name())
     }
 
+    /// Returns the assigned name for a KnownValue, or None if the store has
+    /// no name for it.
+    ///
+    /// This takes the KnownValue by value like [`KnownValuesStore::name`], but
+    /// never falls back to the numeric value. It delegates to
+    /// [`KnownValuesStore::assigned_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    ///
+    /// assert_eq!(store.name_opt(known_values::IS_A), Some("isA"));
+    /// assert_eq!(store.name_opt(KnownValue::new(999)), None);
+    ///
+    /// // Names carried by the argument itself are not consulted
+    /// let unregistered = KnownValue::new_with_name(999u64, "other".to_string());
+    /// assert_eq!(store.name_opt(unregistered), None);
+    /// ```
+    pub fn name_opt(&self, known_value: KnownValue) -> Option<&str> {
+        self.assigned_name(&known_value)
+    }
+
+    /// Returns the display name of a codepoint for a language tag, falling
+    /// back to its assigned name.
+    ///
+    /// See [`KnownValue::localized_name`] for how language tags are matched.
+    /// Returns None if the codepoint is not in the store or has neither a
+    /// localized nor an assigned name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let color = KnownValue::new_with_name(1000u64, "color".to_string())
+    ///     .with_localized_name("en-GB", "colour");
+    /// let store = KnownValuesStore::new([color]);
+    ///
+    /// assert_eq!(store.name_localized(1000, "en-GB"), Some("colour"));
+    /// assert_eq!(store.name_localized(1000, "fr"), Some("color"));
+    /// assert_eq!(store.name_localized(999, "en"), None);
+    /// ```
+    pub fn name_localized(&self, value: u64, lang: &str) -> Option<&str> {
+        let known_value = self.get(value)?;
+        known_value
+            .localized_name(lang)
+            .or_else(|| known_value.assigned_name())
+    }
+
     /// Looks up a KnownValue by its assigned name.
     ///
     /// Returns a reference to the KnownValue if found, or None if no KnownValue
@@ -187,14 +370,7 @@ impl KnownValuesStore {
         self.known_values_by_assigned_name.get(assigned_name)
     }
 
-    /// Retrieves a KnownValue for a raw value, using a store if provided.
-    ///
-    /// This static method allows looking up a KnownValue by its raw numeric
-    /// value:
-    /// - If a store is provided and contains a mapping for the raw value, that
-    ///   KnownValue is returned
-    /// - Otherwise, a new KnownValue with no assigned name is created and
-    ///   returned
+    /// Looks up a KnownValue by its codepoint.
     ///
     /// # Examples
     ///
@@ -203,75 +379,97 @@ impl KnownValuesStore {
     ///
     /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
     ///
-    /// // Known value from store
-    /// let is_a = KnownValuesStore::known_value_for_raw_value(1, Some(&store));
-    /// assert_eq!(is_a.name(), "isA");
+    /// assert_eq!(store.get(4).unwrap().name(), "note");
+    /// assert!(store.get(999).is_none());
+    /// ```
+    pub fn get(&self, codepoint: u64) -> Option<&KnownValue> {
+        self.known_values_by_raw_value.get(&codepoint)
+    }
+
+    /// Looks up the named KnownValue for a codepoint, failing if the store
+    /// has no named value for it.
     ///
-    /// // Unknown value creates a new KnownValue
-    /// let unknown =
-    ///     KnownValuesStore::known_value_for_raw_value(999, Some(&store));
-    /// assert_eq!(unknown.name(), "999");
+    /// This suits strict decoders that must reject unknown codepoints rather
+    /// than keep them as bare values, as
+    /// [`KnownValuesStore::known_value_for_raw_value`] does. A value stored
+    /// without an assigned name is rejected too, since it is no more known
+    /// than a bare value.
+    ///
+    /// # Examples
     ///
-    /// // No store provided also creates a new KnownValue
-    /// let unknown = KnownValuesStore::known_value_for_raw_value(1, None);
-    /// assert_eq!(unknown.name(), "1");
     /// ```
-    pub fn known_value_for_raw_value(
-        raw_value: u64,
-        known_values: Option<&Self>,
-    ) -> KnownValue {
-        known_values
-            .and_then(|known_values| {
-                known_values.known_values_by_raw_value.get(&raw_value)
-            })
-            .cloned()
-            .unwrap_or_else(|| KnownValue::new(raw_value))
+    /// use known_values::{KnownValue, KnownValuesStore, UnknownCodepoint};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::IS_A,
+    ///     known_values::NOTE,
+    ///     KnownValue::new(5),
+    /// ]);
+    ///
+    /// assert_eq!(store.require_named(4).unwrap().name(), "note");
+    /// assert_eq!(
+    ///     store.require_named(999),
+    ///     Err(UnknownCodepoint { codepoint: 999 })
+    /// );
+    /// assert_eq!(
+    ///     store.require_named(5),
+    ///     Err(UnknownCodepoint { codepoint: 5 })
+    /// );
+    /// ```
+    pub fn require_named(
+        &self,
+        value: u64,
+    ) -> Result<&KnownValue, UnknownCodepoint> {
+        self.get(value)
+            .filter(|known_value| known_value.assigned_name().is_some())
+            .ok_or(UnknownCodepoint { codepoint: value })
     }
 
-    /// Attempts to find a KnownValue by its name, using a store if provided.
+    /// Resolves a placeholder created by [`KnownValue::unresolved`] to the
+    /// store's KnownValue with the same assigned name.
     ///
-    /// This static method allows looking up a KnownValue by its name:
-    /// - If a store is provided and contains a mapping for the name, that
-    ///   KnownValue is returned
-    /// - Otherwise, None is returned
+    /// Already-resolved values are returned unchanged. Returns None if the
+    /// value is unresolved and the store has no value with its name.
     ///
     /// # Examples
     ///
     /// ```
-    /// use known_values::KnownValuesStore;
+    /// use known_values::{KnownValue, KnownValuesStore};
     ///
     /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
     ///
-    /// // Known value from store
-    /// let is_a = KnownValuesStore::known_value_for_name("isA", Some(&store));
-    /// assert_eq!(is_a.unwrap().value(), 1);
+    /// let resolved = store.resolve_unresolved(&KnownValue::unresolved("note"));
+    /// assert_eq!(resolved, Some(known_values::NOTE));
+    /// assert!(resolved.unwrap().is_resolved());
     ///
-    /// // Unknown name returns None
-    /// assert!(
-    ///     KnownValuesStore::known_value_for_name("unknown", Some(&store))
-    ///         .is_none()
+    /// assert_eq!(
+    ///     store.resolve_unresolved(&KnownValue::unresolved("missing")),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     store.resolve_unresolved(&KnownValue::new(42)),
+    ///     Some(KnownValue::new(42))
     /// );
-    ///
-    /// // No store provided also returns None
-    /// assert!(KnownValuesStore::known_value_for_name("isA", None).is_none());
     /// ```
-    pub fn known_value_for_name(
-        name: &str,
-        known_values: Option<&Self>,
+    pub fn resolve_unresolved(
+        &self,
+        known_value: &KnownValue,
     ) -> Option<KnownValue> {
-        known_values
-            .and_then(|known_values| known_values.known_value_named(name))
+        if known_value.is_resolved() {
+            return Some(known_value.clone());
+        }
+        self.known_value_named(known_value.assigned_name()?)
             .cloned()
     }
 
-    /// Returns a human-readable name for a KnownValue, using a store if
-    /// provided.
+    /// Looks up a KnownValue by name, constructing one on a miss.
     ///
-    /// This static method allows getting a name for a KnownValue:
-    /// - If a store is provided and contains a mapping for the KnownValue, its
-    ///   assigned name is returned
-    /// - Otherwise, the KnownValue's default name (which may be its numeric
-    ///   value as a string) is returned
+    /// The name is resolved in this order:
+    /// 1. A stored value with that assigned name is returned as is.
+    /// 2. Otherwise, if `name` parses as a `u64`, a bare value with that
+    ///    codepoint is returned, even if the store has a value there.
+    /// 3. Otherwise, an unresolved value carrying `name` is returned; see
+    ///    [`KnownValue::unresolved`].
     ///
     /// # Examples
     ///
@@ -280,114 +478,1090 @@ impl KnownValuesStore {
     ///
     /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
     ///
-    /// // Known value from store
-    /// let name = KnownValuesStore::name_for_known_value(
-    ///     known_values::IS_A,
-    ///     Some(&store),
+    /// assert!(
+    ///     store
+    ///         .known_value_named_or_new("note")
+    ///         .is_exactly(&known_values::NOTE)
     /// );
-    /// assert_eq!(name, "isA");
-    ///
-    /// // Unknown value in store uses KnownValue's name method
-    /// let name = KnownValuesStore::name_for_known_value(
-    ///     KnownValue::new(999),
-    ///     Some(&store),
+    /// assert!(
+    ///     store
+    ///         .known_value_named_or_new("1000")
+    ///         .is_exactly(&KnownValue::new(1000))
     /// );
-    /// assert_eq!(name, "999");
     ///
-    /// // No store provided also uses KnownValue's name method
-    /// let name = KnownValuesStore::name_for_known_value(known_values::IS_A, None);
-    /// assert_eq!(name, "isA");
+    /// let placeholder = store.known_value_named_or_new("missing");
+    /// assert!(!placeholder.is_resolved());
+    /// assert_eq!(placeholder.assigned_name(), Some("missing"));
     /// ```
-    pub fn name_for_known_value(
-        known_value: KnownValue,
-        known_values: Option<&Self>,
-    ) -> String {
-        known_values
-            .and_then(|known_values| known_values.assigned_name(&known_value))
-            .map(|assigned_name| assigned_name.to_string())
-            .unwrap_or_else(|| known_value.name())
-    }
-
-    /// Internal helper method to insert a KnownValue into the store's maps.
-    ///
-    /// When inserting a value with a codepoint that already exists, this method
-    /// removes the old name from the name index before adding the new one.
-    fn _insert(
-        known_value: KnownValue,
-        known_values_by_raw_value: &mut HashMap<u64, KnownValue>,
-        known_values_by_assigned_name: &mut HashMap<String, KnownValue>,
-    ) {
-        // If there's an existing value with the same codepoint, remove its name
-        // from the name index to avoid stale entries
-        if let Some(old_value) =
-            known_values_by_raw_value.get(&known_value.value())
-            && let Some(old_name) = old_value.assigned_name()
-        {
-            known_values_by_assigned_name.remove(old_name);
+    pub fn known_value_named_or_new(&self, name: &str) -> KnownValue {
+        if let Some(known_value) = self.known_value_named(name) {
+            return known_value.clone();
         }
-
-        known_values_by_raw_value
-            .insert(known_value.value(), known_value.clone());
-        if let Some(name) = known_value.assigned_name() {
-            known_values_by_assigned_name.insert(name.to_string(), known_value);
+        match name.parse::<u64>() {
+            Ok(codepoint) => KnownValue::new(codepoint),
+            Err(_) => KnownValue::unresolved(name),
         }
     }
 
-    /// Loads and inserts known values from a directory containing JSON registry
-    /// files.
-    ///
-    /// This method scans the specified directory for `.json` files and parses
-    /// them as known value registries. Values from JSON files override
-    /// existing values in the store when codepoints match.
+    /// Looks up a KnownValue by its ontology URI.
     ///
-    /// This method is only available when the `directory-loading` feature is
-    /// enabled.
+    /// Only values carrying a URI, typically loaded from a registry file, are
+    /// indexed. If several values share a URI, the most recently inserted one
+    /// is returned.
     ///
-    /// # Arguments
+    /// # Examples
     ///
-    /// * `path` - The directory to scan for JSON registry files.
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
     ///
-    /// # Returns
+    /// let store = KnownValuesStore::new([
+    ///     known_values::IS_A,
+    ///     KnownValue::new_with_name(1000u64, "color".to_string())
+    ///         .with_uri("https://example.com/vocab#color"),
+    /// ]);
     ///
-    /// Returns `Ok(count)` with the number of values loaded, or an error if
-    /// directory traversal fails.
+    /// let color = store
+    ///     .known_value_by_uri("https://example.com/vocab#color")
+    ///     .unwrap();
+    /// assert_eq!(color.value(), 1000);
+    /// assert!(
+    ///     store
+    ///         .known_value_by_uri("https://example.com/vocab#size")
+    ///         .is_none()
+    /// );
+    /// ```
+    pub fn known_value_by_uri(&self, uri: &str) -> Option<&KnownValue> {
+        self.known_values_by_uri
+            .get(uri)
+            .and_then(|codepoint| self.known_values_by_raw_value.get(codepoint))
+    }
+
+    /// Returns the codepoint of the KnownValue with the given assigned name.
     ///
     /// # Examples
     ///
-    /// ```rust,ignore
+    /// ```
     /// use known_values::KnownValuesStore;
-    /// use std::path::Path;
     ///
-    /// let mut store = KnownValuesStore::default();
-    /// let count = store.load_from_directory(Path::new("/etc/known-values"))?;
-    /// println!("Loaded {} values", count);
+    /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    ///
+    /// assert_eq!(store.codepoint_of("note"), Some(4));
+    /// assert_eq!(store.codepoint_of("nonexistent"), None);
     /// ```
-    #[cfg(feature = "directory-loading")]
-    pub fn load_from_directory(
-        &mut self,
-        path: &Path,
-    ) -> Result<usize, crate::LoadError> {
-        let values = crate::directory_loader::load_from_directory(path)?;
-        let count = values.len();
-        for value in values {
-            self.insert(value);
-        }
-        Ok(count)
+    pub fn codepoint_of(&self, name: &str) -> Option<u64> {
+        self.known_value_named(name).map(KnownValue::value)
     }
 
-    /// Loads known values from multiple directories using the provided
-    /// configuration.
+    /// Returns the assigned name of a codepoint, or None if the store has no
+    /// name for it.
     ///
-    /// Directories are processed in order. When multiple entries have the same
-    /// codepoint, values from later directories override values from earlier
-    /// directories.
+    /// Unlike [`KnownValuesStore::name`], this never falls back to the
+    /// numeric value.
     ///
-    /// This method is only available when the `directory-loading` feature is
-    /// enabled.
+    /// # Examples
     ///
-    /// # Arguments
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
     ///
-    /// * `config` - The directory configuration specifying search paths.
+    /// let store =
+    ///     KnownValuesStore::new([known_values::IS_A, KnownValue::new(42)]);
+    ///
+    /// assert_eq!(store.name_of(1), Some("isA"));
+    /// assert_eq!(store.name_of(42), None);
+    /// assert_eq!(store.name_of(999), None);
+    /// ```
+    pub fn name_of(&self, codepoint: u64) -> Option<&str> {
+        self.get(codepoint).and_then(KnownValue::assigned_name)
+    }
+
+    /// Returns true if the store maps the KnownValue's codepoint to the same
+    /// assigned name.
+    ///
+    /// This is stricter than KnownValue equality, which compares codepoints
+    /// only. It is useful for verifying that a decoded value matches the
+    /// registry exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    ///
+    /// assert!(store.contains(&known_values::IS_A));
+    /// assert!(
+    ///     store.contains(&KnownValue::new_with_name(1u64, "isA".to_string()))
+    /// );
+    ///
+    /// // Same codepoint, different name
+    /// assert!(
+    ///     !store.contains(&KnownValue::new_with_name(1u64, "other".to_string()))
+    /// );
+    /// assert!(!store.contains(&KnownValue::new(1)));
+    ///
+    /// // Codepoint not present
+    /// assert!(!store.contains(&known_values::SIGNED));
+    /// ```
+    pub fn contains(&self, known_value: &KnownValue) -> bool {
+        self.known_values_by_raw_value
+            .get(&known_value.value())
+            .is_some_and(|stored| {
+                stored.assigned_name() == known_value.assigned_name()
+            })
+    }
+
+    /// Returns an iterator over `(codepoint, assigned name)` pairs, in no
+    /// particular order.
+    ///
+    /// This is a lightweight projection convenient for building tables,
+    /// exports, and test assertions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::NOTE,
+    ///     known_values::IS_A,
+    ///     KnownValue::new(42),
+    /// ]);
+    ///
+    /// let mut entries: Vec<_> = store.entries().collect();
+    /// entries.sort();
+    /// assert_eq!(
+    ///     entries,
+    ///     vec![(1, Some("isA")), (4, Some("note")), (42, None)]
+    /// );
+    /// ```
+    pub fn entries(&self) -> impl Iterator<Item = (u64, Option<&str>)> {
+        self.known_values_by_raw_value
+            .iter()
+            .map(|(codepoint, known_value)| {
+                (*codepoint, known_value.assigned_name())
+            })
+    }
+
+    /// Returns an iterator over the values in the store in ascending
+    /// codepoint order.
+    ///
+    /// The order is materialized when this is called, so the iterator
+    /// borrows the store but does not clone any values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::NOTE,
+    ///     KnownValue::new(42),
+    ///     known_values::IS_A,
+    /// ]);
+    ///
+    /// let names: Vec<_> = store.iter_by_codepoint().map(|v| v.name()).collect();
+    /// assert_eq!(names, vec!["isA", "note", "42"]);
+    /// ```
+    pub fn iter_by_codepoint(&self) -> impl Iterator<Item = &KnownValue> {
+        let mut known_values: Vec<_> =
+            self.known_values_by_raw_value.values().collect();
+        known_values.sort_by_key(|known_value| known_value.value());
+        known_values.into_iter()
+    }
+
+    /// Returns up to `limit` values starting at position `offset`, in
+    /// ascending codepoint order.
+    ///
+    /// Ordering by codepoint keeps pages stable for an unchanged store, so
+    /// consecutive pages neither overlap nor skip values. An `offset` past
+    /// the end yields an empty page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::NOTE,
+    ///     known_values::SIGNED,
+    ///     known_values::IS_A,
+    /// ]);
+    ///
+    /// let page: Vec<_> = store
+    ///     .page(1, 2)
+    ///     .into_iter()
+    ///     .map(KnownValue::value)
+    ///     .collect();
+    /// assert_eq!(page, vec![3, 4]);
+    /// assert!(store.page(3, 2).is_empty());
+    /// ```
+    pub fn page(&self, offset: usize, limit: usize) -> Vec<&KnownValue> {
+        self.iter_by_codepoint().skip(offset).take(limit).collect()
+    }
+
+    /// Returns the codepoints of all values in the store, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::NOTE,
+    ///     KnownValue::new(42),
+    ///     known_values::IS_A,
+    /// ]);
+    /// assert_eq!(store.codepoints_sorted(), vec![1, 4, 42]);
+    /// ```
+    pub fn codepoints_sorted(&self) -> Vec<u64> {
+        let mut codepoints: Vec<_> =
+            self.known_values_by_raw_value.keys().copied().collect();
+        codepoints.sort_unstable();
+        codepoints
+    }
+
+    /// Returns clones of all values in the store, sorted by codepoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::NOTE,
+    ///     KnownValue::new(42),
+    ///     known_values::IS_A,
+    /// ]);
+    ///
+    /// let codepoints: Vec<_> =
+    ///     store.to_vec().iter().map(KnownValue::value).collect();
+    /// assert_eq!(codepoints, vec![1, 4, 42]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<KnownValue> {
+        self.iter_by_codepoint().cloned().collect()
+    }
+
+    /// Returns clones of all values in the store, sorted by assigned name.
+    ///
+    /// Values without an assigned name come last, sorted by codepoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     KnownValue::new(42),
+    ///     known_values::SIGNED,
+    ///     KnownValue::new(7),
+    ///     known_values::IS_A,
+    ///     known_values::NOTE,
+    /// ]);
+    ///
+    /// let names: Vec<_> = store
+    ///     .to_vec_by_name()
+    ///     .iter()
+    ///     .map(KnownValue::name)
+    ///     .collect();
+    /// assert_eq!(names, vec!["isA", "note", "signed", "7", "42"]);
+    /// ```
+    pub fn to_vec_by_name(&self) -> Vec<KnownValue> {
+        let mut known_values: Vec<_> =
+            self.known_values_by_raw_value.values().cloned().collect();
+        fn key(known_value: &KnownValue) -> (bool, Option<&str>, u64) {
+            let name = known_value.assigned_name();
+            (name.is_none(), name, known_value.value())
+        }
+        known_values.sort_by(|a, b| key(a).cmp(&key(b)));
+        known_values
+    }
+
+    /// Returns every assigned name in the store, in lexical order.
+    ///
+    /// Values without an assigned name contribute nothing; numeric fallback
+    /// names are never included. Names are compared bytewise, so uppercase
+    /// names sort before lowercase ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::SIGNED,
+    ///     KnownValue::new(42),
+    ///     known_values::SELF,
+    ///     known_values::IS_A,
+    ///     known_values::NOTE,
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     store.assigned_names_sorted(),
+    ///     vec!["Self", "isA", "note", "signed"]
+    /// );
+    /// ```
+    pub fn assigned_names_sorted(&self) -> Vec<&str> {
+        let mut names: Vec<_> = self
+            .known_values_by_assigned_name
+            .keys()
+            .map(|name| &**name)
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns the values whose codepoints lie in `range` and whose assigned
+    /// names start with `prefix`, sorted by codepoint.
+    ///
+    /// The prefix match is case-sensitive and only considers assigned names,
+    /// so values without one never match. This suits pickers that narrow to
+    /// a category's codepoint range before filtering by typed text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::with_builtins();
+    ///
+    /// let names: Vec<_> = store
+    ///     .names_with_prefix_in_range("parent", 500..=599)
+    ///     .into_iter()
+    ///     .map(KnownValue::name)
+    ///     .collect();
+    /// assert_eq!(names, vec!["parentPath", "parentFingerprint"]);
+    /// ```
+    pub fn names_with_prefix_in_range(
+        &self,
+        prefix: &str,
+        range: RangeInclusive<u64>,
+    ) -> Vec<&KnownValue> {
+        let mut known_values: Vec<_> = self
+            .known_values_by_raw_value
+            .values()
+            .filter(|known_value| {
+                range.contains(&known_value.value())
+                    && known_value
+                        .assigned_name()
+                        .is_some_and(|name| name.starts_with(prefix))
+            })
+            .collect();
+        known_values.sort_by_key(|known_value| known_value.value());
+        known_values
+    }
+
+    /// Returns references to all values in the store, sorted by the given
+    /// comparator.
+    ///
+    /// The sort is stable, but the store's iteration order is unspecified, so
+    /// the comparator should break ties itself when a deterministic order is
+    /// needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::SIGNED,
+    ///     known_values::IS_A,
+    ///     known_values::NOTE,
+    ///     known_values::ID,
+    /// ]);
+    ///
+    /// // Shortest names first, ties broken by codepoint
+    /// let names: Vec<_> = store
+    ///     .iter_sorted_by(|a, b| {
+    ///         a.name()
+    ///             .len()
+    ///             .cmp(&b.name().len())
+    ///             .then(a.value().cmp(&b.value()))
+    ///     })
+    ///     .into_iter()
+    ///     .map(KnownValue::name)
+    ///     .collect();
+    /// assert_eq!(names, vec!["id", "isA", "note", "signed"]);
+    /// ```
+    pub fn iter_sorted_by<F>(&self, mut cmp: F) -> Vec<&KnownValue>
+    where
+        F: FnMut(&KnownValue, &KnownValue) -> Ordering,
+    {
+        let mut known_values: Vec<_> =
+            self.known_values_by_raw_value.values().collect();
+        known_values.sort_by(|a, b| cmp(a, b));
+        known_values
+    }
+
+    /// Returns the lowest codepoint in the store, or None if the store is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let store = KnownValuesStore::new([known_values::NOTE, known_values::IS_A]);
+    /// assert_eq!(store.min_codepoint(), Some(1));
+    ///
+    /// assert_eq!(KnownValuesStore::default().min_codepoint(), None);
+    /// ```
+    pub fn min_codepoint(&self) -> Option<u64> {
+        self.known_values_by_raw_value.keys().min().copied()
+    }
+
+    /// Returns the highest codepoint in the store, or None if the store is
+    /// empty.
+    ///
+    /// This is useful for tooling that allocates new codepoints, which can
+    /// assign `max + 1` to the next registered value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let store = KnownValuesStore::new([known_values::NOTE, known_values::IS_A]);
+    /// assert_eq!(store.max_codepoint(), Some(4));
+    ///
+    /// assert_eq!(KnownValuesStore::default().max_codepoint(), None);
+    /// ```
+    pub fn max_codepoint(&self) -> Option<u64> {
+        self.known_values_by_raw_value.keys().max().copied()
+    }
+
+    /// Retrieves a KnownValue for a raw value, using a store if provided.
+    ///
+    /// This static method allows looking up a KnownValue by its raw numeric
+    /// value:
+    /// - If a store is provided and contains a mapping for the raw value, that
+    ///   KnownValue is returned
+    /// - Otherwise, a new KnownValue with no assigned name is created and
+    ///   returned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    ///
+    /// // Known value from store
+    /// let is_a = KnownValuesStore::known_value_for_raw_value(1, Some(&store));
+    /// assert_eq!(is_a.name(), "isA");
+    ///
+    /// // Unknown value creates a new KnownValue
+    /// let unknown =
+    ///     KnownValuesStore::known_value_for_raw_value(999, Some(&store));
+    /// assert_eq!(unknown.name(), "999");
+    ///
+    /// // No store provided also creates a new KnownValue
+    /// let unknown = KnownValuesStore::known_value_for_raw_value(1, None);
+    /// assert_eq!(unknown.name(), "1");
+    /// ```
+    pub fn known_value_for_raw_value(
+        raw_value: u64,
+        known_values: Option<&Self>,
+    ) -> KnownValue {
+        known_values
+            .and_then(|known_values| {
+                known_values.known_values_by_raw_value.get(&raw_value)
+            })
+            .cloned()
+            .unwrap_or_else(|| KnownValue::new(raw_value))
+    }
+
+    /// Attempts to find a KnownValue by its name, using a store if provided.
+    ///
+    /// This static method allows looking up a KnownValue by its name:
+    /// - If a store is provided and contains a mapping for the name, that
+    ///   KnownValue is returned
+    /// - Otherwise, None is returned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    ///
+    /// // Known value from store
+    /// let is_a = KnownValuesStore::known_value_for_name("isA", Some(&store));
+    /// assert_eq!(is_a.unwrap().value(), 1);
+    ///
+    /// // Unknown name returns None
+    /// assert!(
+    ///     KnownValuesStore::known_value_for_name("unknown", Some(&store))
+    ///         .is_none()
+    /// );
+    ///
+    /// // No store provided also returns None
+    /// assert!(KnownValuesStore::known_value_for_name("isA", None).is_none());
+    /// ```
+    pub fn known_value_for_name(
+        name: &str,
+        known_values: Option<&Self>,
+    ) -> Option<KnownValue> {
+        known_values
+            .and_then(|known_values| known_values.known_value_named(name))
+            .cloned()
+    }
+
+    /// Returns a human-readable name for a KnownValue, using a store if
+    /// provided.
+    ///
+    /// This static method allows getting a name for a KnownValue:
+    /// - If a store is provided and contains a mapping for the KnownValue, its
+    ///   assigned name is returned
+    /// - Otherwise, the KnownValue's default name (which may be its numeric
+    ///   value as a string) is returned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    ///
+    /// // Known value from store
+    /// let name = KnownValuesStore::name_for_known_value(
+    ///     known_values::IS_A,
+    ///     Some(&store),
+    /// );
+    /// assert_eq!(name, "isA");
+    ///
+    /// // Unknown value in store uses KnownValue's name method
+    /// let name = KnownValuesStore::name_for_known_value(
+    ///     KnownValue::new(999),
+    ///     Some(&store),
+    /// );
+    /// assert_eq!(name, "999");
+    ///
+    /// // No store provided also uses KnownValue's name method
+    /// let name = KnownValuesStore::name_for_known_value(known_values::IS_A, None);
+    /// assert_eq!(name, "isA");
+    /// ```
+    pub fn name_for_known_value(
+        known_value: KnownValue,
+        known_values: Option<&Self>,
+    ) -> String {
+        known_values
+            .and_then(|known_values| known_values.assigned_name(&known_value))
+            .map(|assigned_name| assigned_name.to_string())
+            .unwrap_or_else(|| known_value.name())
+    }
+
+    /// Returns a new store with `f` applied to every assigned name.
+    ///
+    /// Codepoints are preserved. If `f` returns None, the value is kept
+    /// without a name. Values that have no name are carried over unchanged.
+    /// If `f` maps several values to the same name, only the one with the
+    /// highest codepoint keeps it.
+    ///
+    /// This is useful for normalizing registries loaded from heterogeneous
+    /// sources, e.g. lowercasing or stripping a namespace prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::new([
+    ///     known_values::IS_A,
+    ///     known_values::NOTE,
+    ///     known_values::OK_VALUE,
+    /// ]);
+    ///
+    /// let lowercased = store.filter_map_names(|name| Some(name.to_lowercase()));
+    /// assert_eq!(lowercased.known_value_named("isa").unwrap().value(), 1);
+    /// assert_eq!(lowercased.known_value_named("ok").unwrap().value(), 103);
+    /// assert!(lowercased.known_value_named("isA").is_none());
+    ///
+    /// let dropped = store
+    ///     .filter_map_names(|name| (name != "note").then(|| name.to_string()));
+    /// assert_eq!(dropped.assigned_name(&known_values::NOTE), None);
+    /// assert_eq!(dropped.assigned_name(&known_values::IS_A), Some("isA"));
+    /// ```
+    pub fn filter_map_names<F>(&self, f: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let mut known_values: Vec<_> =
+            self.known_values_by_raw_value.values().collect();
+        known_values.sort_by_key(|known_value| known_value.value());
+        Self::new(known_values.into_iter().map(|known_value| {
+            let name = known_value.assigned_name().and_then(&f);
+            known_value.clone().with_assigned_name(name)
+        }))
+    }
+
+    /// Checks the store's internal invariants.
+    ///
+    /// Every name in the name index must refer to a codepoint in the value
+    /// index whose assigned name matches. Returns the first violation found,
+    /// in name order. This is a safety net and debugging aid for code that
+    /// constructs stores in nonstandard ways.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let mut store =
+    ///     KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// store.insert(KnownValue::new_with_name(1u64, "type".to_string()));
+    /// store.remove(&known_values::NOTE);
+    /// assert!(store.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), StoreInconsistency> {
+        let mut names: Vec<_> =
+            self.known_values_by_assigned_name.iter().collect();
+        names.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (name, indexed) in names {
+            let codepoint = indexed.value();
+            let Some(stored) = self.known_values_by_raw_value.get(&codepoint)
+            else {
+                return Err(StoreInconsistency::MissingCodepoint {
+                    name: name.to_string(),
+                    codepoint,
+                });
+            };
+            if stored.assigned_name() != Some(&**name)
+                || indexed.assigned_name() != Some(&**name)
+            {
+                return Err(StoreInconsistency::NameMismatch {
+                    name: name.to_string(),
+                    codepoint,
+                    stored_name: stored.assigned_name().map(str::to_string),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes a KnownValue from the store by its codepoint.
+    ///
+    /// Returns the removed KnownValue, or None if the codepoint was not
+    /// present. The assigned name is removed from the name index as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let mut store =
+    ///     KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// let removed = store.remove(&known_values::IS_A).unwrap();
+    /// assert_eq!(removed.name(), "isA");
+    /// assert!(store.known_value_named("isA").is_none());
+    /// assert!(store.remove(&known_values::IS_A).is_none());
+    /// ```
+    pub fn remove(&mut self, known_value: &KnownValue) -> Option<KnownValue> {
+        let removed = self
+            .known_values_by_raw_value
+            .remove(&known_value.value())?;
+        if let Some(name) = removed.assigned_name()
+            && self
+                .known_values_by_assigned_name
+                .get(name)
+                .is_some_and(|indexed| indexed.value() == removed.value())
+        {
+            self.known_values_by_assigned_name.remove(name);
+        }
+        if let Some(uri) = removed.uri() {
+            Self::unindex_uri(&mut self.known_values_by_uri, uri, &removed);
+        }
+        Some(removed)
+    }
+
+    /// Removes every value for which `f` returns false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let mut store = KnownValuesStore::with_builtins();
+    /// store.retain(|known_value| known_value.value() < 10);
+    ///
+    /// assert_eq!(store.max_codepoint(), Some(9));
+    /// assert!(store.known_value_named("isA").is_some());
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&KnownValue) -> bool,
+    {
+        let rejected: Vec<_> = self
+            .known_values_by_raw_value
+            .values()
+            .filter(|known_value| !f(known_value))
+            .cloned()
+            .collect();
+        for known_value in &rejected {
+            self.remove(known_value);
+        }
+    }
+
+    /// Removes every value whose codepoint is not in `category`.
+    ///
+    /// Membership is decided by the category's codepoint range, so loaded
+    /// values in the range are kept along with the builtins. This is the
+    /// in-place counterpart of [`KnownValuesStore::subset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{Category, KnownValuesStore};
+    ///
+    /// let mut store = KnownValuesStore::with_builtins();
+    /// store.retain_category(Category::Bitcoin);
+    ///
+    /// assert_eq!(store.codepoint_of("chainCode"), Some(501));
+    /// assert_eq!(store.codepoint_of("isA"), None);
+    /// ```
+    pub fn retain_category(&mut self, category: Category) {
+        self.retain(|known_value| known_value.category() == category);
+    }
+
+    /// Returns a new store containing the values whose codepoints are present
+    /// in this store but not in `other`.
+    ///
+    /// Assigned names are not compared, and neither store is modified. This is
+    /// useful for finding local extensions that an upstream registry lacks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let local = KnownValuesStore::new([
+    ///     known_values::IS_A,
+    ///     known_values::NOTE,
+    ///     KnownValue::new_with_name(1000u64, "myValue".to_string()),
+    /// ]);
+    /// let upstream = KnownValuesStore::new([
+    ///     KnownValue::new_with_name(1u64, "type".to_string()),
+    ///     known_values::NOTE,
+    ///     known_values::SIGNED,
+    /// ]);
+    ///
+    /// let extensions = local.difference(&upstream);
+    /// assert_eq!(
+    ///     extensions.entries().collect::<Vec<_>>(),
+    ///     vec![(1000, Some("myValue"))]
+    /// );
+    /// assert_eq!(
+    ///     upstream.difference(&local).entries().collect::<Vec<_>>(),
+    ///     vec![(3, Some("signed"))]
+    /// );
+    /// assert!(local.difference(&local).entries().next().is_none());
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        Self::new(self.to_vec().into_iter().filter(|known_value| {
+            !other
+                .known_values_by_raw_value
+                .contains_key(&known_value.value())
+        }))
+    }
+
+    /// Returns a new store with this store's values in `category`.
+    ///
+    /// Membership is decided by the category's codepoint range, so loaded
+    /// values in the range are included along with the builtins, and names
+    /// overridden by loaded registries are kept. The original store is left
+    /// unchanged; see [`KnownValuesStore::retain_category`] for the in-place
+    /// variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{Category, KnownValuesStore};
+    ///
+    /// let store = KnownValuesStore::with_builtins();
+    /// let bitcoin = store.subset(Category::Bitcoin);
+    ///
+    /// assert_eq!(bitcoin.codepoint_of("chainCode"), Some(501));
+    /// assert_eq!(bitcoin.codepoint_of("isA"), None);
+    /// assert_eq!(store.codepoint_of("isA"), Some(1));
+    /// ```
+    pub fn subset(&self, category: Category) -> Self {
+        Self::new(
+            self.known_values_by_raw_value
+                .values()
+                .filter(|known_value| known_value.category() == category)
+                .cloned(),
+        )
+    }
+
+    /// Computes the differences needed to turn this store into `other`.
+    ///
+    /// See [`StoreDiff`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let a = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// let b = KnownValuesStore::new([known_values::IS_A, known_values::SIGNED]);
+    ///
+    /// let diff = a.diff(&b);
+    /// assert_eq!(diff.added, vec![known_values::SIGNED]);
+    /// assert_eq!(diff.removed, vec![known_values::NOTE]);
+    /// assert!(diff.renamed.is_empty());
+    /// assert!(a.diff(&a).is_empty());
+    /// ```
+    pub fn diff(&self, other: &Self) -> StoreDiff {
+        let mut diff = StoreDiff::default();
+        for (codepoint, known_value) in &self.known_values_by_raw_value {
+            match other.known_values_by_raw_value.get(codepoint) {
+                None => diff.removed.push(known_value.clone()),
+                Some(other_value)
+                    if other_value.assigned_name()
+                        != known_value.assigned_name() =>
+                {
+                    diff.renamed
+                        .push((known_value.clone(), other_value.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        for (codepoint, other_value) in &other.known_values_by_raw_value {
+            if !self.known_values_by_raw_value.contains_key(codepoint) {
+                diff.added.push(other_value.clone());
+            }
+        }
+        diff.added.sort_by_key(KnownValue::value);
+        diff.removed.sort_by_key(KnownValue::value);
+        diff.renamed.sort_by_key(|(old, _)| old.value());
+        diff
+    }
+
+    /// Checks this store against a canonical registry, reporting every
+    /// codepoint that both stores define under different assigned names.
+    ///
+    /// Codepoints present in only one store are ignored, so local extensions
+    /// are not flagged. A finding usually means a local registry shadows a
+    /// standard name, which may violate the specification. Findings are
+    /// sorted by codepoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let mut store = KnownValuesStore::with_builtins();
+    /// store.insert(KnownValue::new_with_name(1000u64, "local".to_string()));
+    /// assert!(
+    ///     store
+    ///         .audit_against(&KnownValuesStore::with_builtins())
+    ///         .is_empty()
+    /// );
+    ///
+    /// store.insert(KnownValue::new_with_name(1u64, "type".to_string()));
+    /// let findings = store.audit_against(&KnownValuesStore::with_builtins());
+    /// assert_eq!(findings.len(), 1);
+    /// assert_eq!(
+    ///     findings[0].to_string(),
+    ///     "codepoint 1 is named \"type\" but the canonical name is \"isA\""
+    /// );
+    /// ```
+    pub fn audit_against(&self, canonical: &Self) -> Vec<AuditFinding> {
+        let mut findings: Vec<_> = self
+            .known_values_by_raw_value
+            .iter()
+            .filter_map(|(codepoint, known_value)| {
+                let canonical_value =
+                    canonical.known_values_by_raw_value.get(codepoint)?;
+                let name = known_value.assigned_name();
+                let canonical_name = canonical_value.assigned_name();
+                (name != canonical_name).then(|| AuditFinding {
+                    codepoint: *codepoint,
+                    name: name.map(str::to_string),
+                    canonical_name: canonical_name.map(str::to_string),
+                })
+            })
+            .collect();
+        findings.sort_by_key(|finding| finding.codepoint);
+        findings
+    }
+
+    /// Applies a [`StoreDiff`] to this store.
+    ///
+    /// Removals are applied first, then renames, then additions. Applying the
+    /// diff from `a.diff(&b)` to `a` makes it equal to `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let mut a = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// let b = KnownValuesStore::new([
+    ///     KnownValue::new_with_name(1u64, "type".to_string()),
+    ///     known_values::SIGNED,
+    /// ]);
+    ///
+    /// let diff = a.diff(&b);
+    /// a.apply_diff(&diff);
+    /// assert_eq!(a, b);
+    /// assert!(a.diff(&b).is_empty());
+    /// ```
+    pub fn apply_diff(&mut self, diff: &StoreDiff) {
+        for known_value in &diff.removed {
+            self.remove(known_value);
+        }
+        for (_, new_value) in &diff.renamed {
+            self.insert(new_value.clone());
+        }
+        for known_value in &diff.added {
+            self.insert(known_value.clone());
+        }
+    }
+
+    /// Returns a layered view that consults `extra` first and falls back to
+    /// this store by reference.
+    ///
+    /// See [`OverlayStore`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{KnownValue, KnownValuesStore};
+    ///
+    /// let base = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// let view = base.overlay(KnownValuesStore::new([KnownValue::new_with_name(
+    ///     4u64,
+    ///     "comment".to_string(),
+    /// )]));
+    ///
+    /// assert_eq!(view.name(known_values::NOTE), "comment");
+    /// assert_eq!(base.name(known_values::NOTE), "note");
+    /// ```
+    pub fn overlay(&self, extra: KnownValuesStore) -> OverlayStore<'_> {
+        OverlayStore::new(self, extra)
+    }
+
+    /// Internal helper method to insert a KnownValue into the store's maps.
+    ///
+    /// When inserting a value with a codepoint that already exists, this method
+    /// removes the old name from the name index before adding the new one.
+    /// When inserting a value whose name is already assigned to a different
+    /// codepoint, that codepoint is kept but loses its name, so both maps stay
+    /// consistent.
+    ///
+    /// The name index key shares the allocation of an interned name, so
+    /// values created with [`KnownValue::interned`] keep sharing their name
+    /// once stored. Other names are not interned.
+    fn _insert(
+        known_value: KnownValue,
+        known_values_by_raw_value: &mut HashMap<u64, KnownValue>,
+        known_values_by_assigned_name: &mut HashMap<Arc<str>, KnownValue>,
+        known_values_by_uri: &mut HashMap<String, u64>,
+    ) {
+        // If there's an existing value with the same codepoint, remove its name
+        // and URI from the indexes to avoid stale entries
+        if let Some(old_value) =
+            known_values_by_raw_value.get(&known_value.value())
+        {
+            if let Some(old_name) = old_value.assigned_name() {
+                known_values_by_assigned_name.remove(old_name);
+            }
+            if let Some(old_uri) = old_value.uri() {
+                Self::unindex_uri(known_values_by_uri, old_uri, old_value);
+            }
+        }
+
+        // If the name is already assigned to a different codepoint, strip it
+        // from that codepoint so the name resolves only to the new value
+        if let Some(name) = known_value.assigned_name()
+            && let Some(stale) = known_values_by_assigned_name.get(name)
+            && stale.value() != known_value.value()
+        {
+            let stale_codepoint = stale.value();
+            if let Some(stale) =
+                known_values_by_raw_value.remove(&stale_codepoint)
+            {
+                known_values_by_raw_value
+                    .insert(stale_codepoint, stale.with_assigned_name(None));
+            }
+        }
+
+        // Only named values need a second copy for the name index
+        if let Some(name) = known_value.shared_name() {
+            known_values_by_assigned_name.insert(name, known_value.clone());
+        }
+        if let Some(uri) = known_value.uri() {
+            known_values_by_uri.insert(uri.to_string(), known_value.value());
+        }
+        known_values_by_raw_value.insert(known_value.value(), known_value);
+    }
+
+    /// Removes `uri` from the URI index if it refers to `known_value`.
+    fn unindex_uri(
+        known_values_by_uri: &mut HashMap<String, u64>,
+        uri: &str,
+        known_value: &KnownValue,
+    ) {
+        if known_values_by_uri.get(uri) == Some(&known_value.value()) {
+            known_values_by_uri.remove(uri);
+        }
+    }
+
+    /// Loads and inserts known values from a directory containing JSON registry
+    /// files.
+    ///
+    /// This method scans the specified directory for `.json` files and parses
+    /// them as known value registries. Values from JSON files override
+    /// existing values in the store when codepoints match.
+    ///
+    /// This method is only available when the `directory-loading` feature is
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The directory to scan for JSON registry files.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(count)` with the number of values loaded, or an error if
+    /// directory traversal fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use known_values::KnownValuesStore;
+    /// use std::path::Path;
+    ///
+    /// let mut store = KnownValuesStore::default();
+    /// let count = store.load_from_directory(Path::new("/etc/known-values"))?;
+    /// println!("Loaded {} values", count);
+    /// ```
+    #[cfg(feature = "directory-loading")]
+    pub fn load_from_directory(
+        &mut self,
+        path: &Path,
+    ) -> Result<usize, crate::LoadError> {
+        let values = crate::directory_loader::load_from_directory(path)?;
+        let count = values.len();
+        for value in values {
+            self.insert(value);
+        }
+        Ok(count)
+    }
+
+    /// Loads known values from multiple directories using the provided
+    /// configuration.
+    ///
+    /// Directories are processed in order. When multiple entries have the same
+    /// codepoint, values from later directories override values from earlier
+    /// directories.
+    ///
+    /// This method is only available when the `directory-loading` feature is
+    /// enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The directory configuration specifying search paths.
     ///
     /// # Returns
     ///
@@ -420,9 +1594,669 @@ impl KnownValuesStore {
         }
         result
     }
+
+    /// Inserts the values of a standalone [`LoadResult`] into this store,
+    /// resolving codepoint collisions with `strategy`.
+    ///
+    /// With [`MergeStrategy::LastWins`] the loaded value replaces the value
+    /// already in the store; with [`MergeStrategy::FirstWins`] the existing
+    /// value is kept. Values are inserted in codepoint order.
+    ///
+    /// This method is only available when the `directory-loading` feature is
+    /// enabled.
+    ///
+    /// [`LoadResult`]: crate::LoadResult
+    /// [`MergeStrategy::LastWins`]: crate::MergeStrategy::LastWins
+    /// [`MergeStrategy::FirstWins`]: crate::MergeStrategy::FirstWins
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::{
+    ///     KnownValue, KnownValuesStore, LoadResult, MergeStrategy, MergeSummary,
+    /// };
+    ///
+    /// let mut result = LoadResult::default();
+    /// for known_value in [
+    ///     KnownValue::new_with_name(1u64, "type".to_string()),
+    ///     KnownValue::new_with_name(1000u64, "custom".to_string()),
+    /// ] {
+    ///     result.values.insert(known_value.value(), known_value);
+    /// }
+    ///
+    /// let mut store = KnownValuesStore::new([known_values::IS_A]);
+    /// let summary = store.merge_result(&result, MergeStrategy::FirstWins);
+    ///
+    /// assert_eq!(
+    ///     summary,
+    ///     MergeSummary { added: 1, overwritten: 0, skipped: 1 }
+    /// );
+    /// assert_eq!(store.name_of(1), Some("isA"));
+    /// assert_eq!(store.codepoint_of("custom"), Some(1000));
+    /// ```
+    #[cfg(feature = "directory-loading")]
+    pub fn merge_result(
+        &mut self,
+        result: &crate::LoadResult,
+        strategy: crate::MergeStrategy,
+    ) -> crate::MergeSummary {
+        let mut values: Vec<_> = result.values.values().collect();
+        values.sort_by_key(|known_value| known_value.value());
+
+        let mut summary = crate::MergeSummary::default();
+        for known_value in values {
+            if !self
+                .known_values_by_raw_value
+                .contains_key(&known_value.value())
+            {
+                summary.added += 1;
+            } else if strategy == crate::MergeStrategy::FirstWins {
+                summary.skipped += 1;
+                continue;
+            } else {
+                summary.overwritten += 1;
+            }
+            self.insert(known_value.clone());
+        }
+        summary
+    }
+}
+
+/// Formats the store as a map from codepoint to assigned name, sorted by
+/// codepoint so that the output is stable across runs.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{KnownValue, KnownValuesStore};
+///
+/// let store = KnownValuesStore::new([
+///     known_values::NOTE,
+///     known_values::IS_A,
+///     KnownValue::new(42),
+/// ]);
+/// assert_eq!(format!("{:?}", store), r#"{1: "isA", 4: "note", 42: None}"#);
+/// ```
+impl fmt::Debug for KnownValuesStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut codepoints: Vec<_> =
+            self.known_values_by_raw_value.keys().collect();
+        codepoints.sort();
+        let mut map = f.debug_map();
+        for codepoint in codepoints {
+            match self.known_values_by_raw_value[codepoint].assigned_name() {
+                Some(name) => map.entry(codepoint, &name),
+                None => map.entry(codepoint, &format_args!("None")),
+            };
+        }
+        map.finish()
+    }
+}
+
+/// An internal inconsistency detected by [`KnownValuesStore::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StoreInconsistency {
+    /// A name in the name index refers to a codepoint that is not in the
+    /// value index.
+    MissingCodepoint {
+        /// The indexed name.
+        name: String,
+        /// The codepoint the name refers to.
+        codepoint: u64,
+    },
+    /// A name in the name index refers to a codepoint whose stored value has a
+    /// different assigned name.
+    NameMismatch {
+        /// The indexed name.
+        name: String,
+        /// The codepoint the name refers to.
+        codepoint: u64,
+        /// The name actually assigned to the stored value, if any.
+        stored_name: Option<String>,
+    },
+}
+
+impl fmt::Display for StoreInconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreInconsistency::MissingCodepoint { name, codepoint } => {
+                write!(
+                    f,
+                    "name \"{}\" refers to missing codepoint {}",
+                    name, codepoint
+                )
+            }
+            StoreInconsistency::NameMismatch {
+                name,
+                codepoint,
+                stored_name,
+            } => {
+                write!(
+                    f,
+                    "name \"{}\" refers to codepoint {}, which is named {:?}",
+                    name, codepoint, stored_name
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreInconsistency {}
+
+/// Error returned by [`KnownValuesStore::require_named`] when the store has no
+/// named value for a codepoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownCodepoint {
+    /// The codepoint that was not found, or was found without a name.
+    pub codepoint: u64,
+}
+
+impl fmt::Display for UnknownCodepoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown codepoint {}", self.codepoint)
+    }
+}
+
+impl std::error::Error for UnknownCodepoint {}
+
+/// A codepoint whose assigned name differs from a canonical registry, as
+/// reported by [`KnownValuesStore::audit_against`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditFinding {
+    /// The codepoint both stores define.
+    pub codepoint: u64,
+    /// The name assigned in the audited store, if any.
+    pub name: Option<String>,
+    /// The name assigned in the canonical store, if any.
+    pub canonical_name: Option<String>,
+}
+
+impl fmt::Display for AuditFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let describe = |name: &Option<String>| match name {
+            Some(name) => format!("\"{}\"", name),
+            None => "unnamed".to_string(),
+        };
+        match (&self.name, &self.canonical_name) {
+            (Some(name), Some(canonical_name)) => write!(
+                f,
+                "codepoint {} is named \"{}\" but the canonical name is \"{}\"",
+                self.codepoint, name, canonical_name
+            ),
+            (name, canonical_name) => write!(
+                f,
+                "codepoint {} is {} but canonically {}",
+                self.codepoint,
+                describe(name),
+                describe(canonical_name)
+            ),
+        }
+    }
+}
+
+/// Two stores are equal when they map the same codepoints to the same assigned
+/// names.
+impl PartialEq for KnownValuesStore {
+    fn eq(&self, other: &Self) -> bool {
+        self.known_values_by_raw_value.len()
+            == other.known_values_by_raw_value.len()
+            && self.diff(other).is_empty()
+    }
+}
+
+impl Eq for KnownValuesStore {}
+
+impl Clone for KnownValuesStore {
+    fn clone(&self) -> Self {
+        Self {
+            known_values_by_raw_value: self.known_values_by_raw_value.clone(),
+            known_values_by_assigned_name: self
+                .known_values_by_assigned_name
+                .clone(),
+            known_values_by_uri: self.known_values_by_uri.clone(),
+        }
+    }
+
+    /// Replaces the contents of this store with a copy of `source`, reusing
+    /// this store's existing allocations where possible.
+    ///
+    /// This avoids allocating fresh maps when a scratch store is refilled
+    /// repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_values::KnownValuesStore;
+    ///
+    /// let source =
+    ///     KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+    /// let mut scratch = KnownValuesStore::new([known_values::SIGNED]);
+    ///
+    /// scratch.clone_from(&source);
+    /// assert_eq!(scratch, source);
+    /// assert!(scratch.known_value_named("signed").is_none());
+    /// ```
+    fn clone_from(&mut self, source: &Self) {
+        self.known_values_by_raw_value.clear();
+        self.known_values_by_raw_value.extend(
+            source.known_values_by_raw_value.iter().map(
+                |(codepoint, known_value)| (*codepoint, known_value.clone()),
+            ),
+        );
+        self.known_values_by_assigned_name.clear();
+        self.known_values_by_assigned_name.extend(
+            source
+                .known_values_by_assigned_name
+                .iter()
+                .map(|(name, known_value)| (name.clone(), known_value.clone())),
+        );
+        self.known_values_by_uri
+            .clone_from(&source.known_values_by_uri);
+    }
 }
 
 /// Default implementation creates an empty KnownValuesStore.
 impl Default for KnownValuesStore {
     fn default() -> Self { Self::new([]) }
 }
+
+#[cfg(test)]
+impl KnownValuesStore {
+    /// Gives tests direct access to the name index so that corrupted stores
+    /// can be constructed.
+    fn name_index_mut(&mut self) -> &mut HashMap<Arc<str>, KnownValue> {
+        &mut self.known_values_by_assigned_name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_reused_name_strips_stale_codepoint() {
+        let mut store = KnownValuesStore::default();
+        store.insert(KnownValue::new_with_name(10u64, "foo".to_string()));
+        store.insert(KnownValue::new_with_name(20u64, "foo".to_string()));
+
+        assert_eq!(store.known_value_named("foo").unwrap().value(), 20);
+        assert_eq!(store.assigned_name(&KnownValue::new(10)), None);
+        assert_eq!(store.assigned_name(&KnownValue::new(20)), Some("foo"));
+        assert_eq!(store.entries().count(), 2);
+        assert_eq!(store.validate(), Ok(()));
+
+        // Reinserting the same codepoint under the same name is a no-op
+        store.insert(KnownValue::new_with_name(20u64, "foo".to_string()));
+        assert_eq!(store.known_value_named("foo").unwrap().value(), 20);
+        assert_eq!(store.entries().count(), 2);
+    }
+
+    #[test]
+    fn test_codepoint_of_and_name_of_are_inverse() {
+        let mut store = KnownValuesStore::new([
+            crate::IS_A,
+            crate::ID,
+            crate::NOTE,
+            crate::SIGNED,
+        ]);
+        for (codepoint, name) in store.entries() {
+            let name = name.unwrap();
+            assert_eq!(store.codepoint_of(name), Some(codepoint));
+            assert_eq!(store.name_of(codepoint), Some(name));
+        }
+
+        // Renaming a codepoint retires its old name
+        store.insert(KnownValue::new_with_name(1u64, "type".to_string()));
+        assert_eq!(store.codepoint_of("type"), Some(1));
+        assert_eq!(store.codepoint_of("isA"), None);
+        assert_eq!(store.name_of(1), Some("type"));
+    }
+
+    #[test]
+    fn test_with_builtins_is_independent_of_global() {
+        let mut store = KnownValuesStore::with_builtins();
+        assert_eq!(store.codepoint_of("isA"), Some(1));
+        assert_eq!(store.codepoint_of("note"), Some(4));
+        assert_eq!(store.entries().count(), BUILTINS.len());
+
+        store.insert(KnownValue::new_with_name(
+            1u64,
+            "withBuiltinsLocal".to_string(),
+        ));
+        assert_eq!(store.codepoint_of("withBuiltinsLocal"), Some(1));
+
+        let binding = crate::KNOWN_VALUES.get();
+        let global = binding.as_ref().unwrap();
+        assert_eq!(global.codepoint_of("isA"), Some(1));
+        assert_eq!(global.codepoint_of("withBuiltinsLocal"), None);
+        assert_eq!(KnownValuesStore::with_builtins().name_of(1), Some("isA"));
+    }
+
+    #[test]
+    fn test_clone_from_reuses_target() {
+        let source = KnownValuesStore::with_builtins();
+        let mut target =
+            KnownValuesStore::new((1000u64..1100).map(|codepoint| {
+                KnownValue::new_with_name(codepoint, format!("v{codepoint}"))
+            }));
+        let capacity = target.known_values_by_raw_value.capacity();
+
+        target.clone_from(&source);
+        assert_eq!(target, source);
+        assert_eq!(target.validate(), Ok(()));
+        assert!(target.known_value_named("v1000").is_none());
+        assert_eq!(target.known_values_by_raw_value.capacity(), capacity);
+
+        // Cloning an empty store clears the target
+        target.clone_from(&KnownValuesStore::default());
+        assert_eq!(target.entries().count(), 0);
+        assert!(target.known_value_named("isA").is_none());
+    }
+
+    #[test]
+    fn test_validate_detects_corruption() {
+        let store = KnownValuesStore::new([crate::IS_A, crate::NOTE]);
+        assert_eq!(store.validate(), Ok(()));
+
+        let mut missing = store.clone();
+        missing.name_index_mut().insert(
+            "ghost".into(),
+            KnownValue::new_with_name(999u64, "ghost".to_string()),
+        );
+        assert_eq!(
+            missing.validate(),
+            Err(StoreInconsistency::MissingCodepoint {
+                name: "ghost".to_string(),
+                codepoint: 999,
+            })
+        );
+
+        let mut mismatched = store.clone();
+        mismatched
+            .name_index_mut()
+            .insert("impostor".into(), crate::IS_A);
+        assert_eq!(
+            mismatched.validate(),
+            Err(StoreInconsistency::NameMismatch {
+                name: "impostor".to_string(),
+                codepoint: 1,
+                stored_name: Some("isA".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_iter_by_codepoint_is_strictly_increasing() {
+        let store = KnownValuesStore::with_builtins();
+        let codepoints: Vec<_> =
+            store.iter_by_codepoint().map(KnownValue::value).collect();
+        assert_eq!(codepoints.len(), BUILTINS.len());
+        assert!(codepoints.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(codepoints, store.codepoints_sorted());
+
+        assert_eq!(KnownValuesStore::default().iter_by_codepoint().count(), 0);
+    }
+
+    #[test]
+    fn test_subset_of_bitcoin_category() {
+        let mut store = KnownValuesStore::with_builtins();
+        store.remove(&crate::PSBT_TYPE);
+        store.insert(KnownValue::new_with_name(
+            crate::CHAIN_CODE.value(),
+            "renamedChainCode".to_string(),
+        ));
+        store.insert(KnownValue::new_with_name(590u64, "loaded".to_string()));
+        store.insert(KnownValue::new_with_name(600u64, "notBitcoin".into()));
+
+        let bitcoin = store.subset(Category::Bitcoin);
+        let mut expected: Vec<_> = (500..=508).chain([590]).collect();
+        expected.retain(|&codepoint| codepoint != crate::PSBT_TYPE.value());
+        assert_eq!(bitcoin.codepoints_sorted(), expected);
+        assert_eq!(bitcoin.name_of(501), Some("renamedChainCode"));
+        assert_eq!(bitcoin.name_of(590), Some("loaded"));
+        assert_eq!(bitcoin.validate(), Ok(()));
+        assert_eq!(store.codepoint_of("isA"), Some(1));
+    }
+
+    #[test]
+    fn test_replace_all_drops_old_values() {
+        let mut store = KnownValuesStore::with_builtins();
+        store.insert(
+            KnownValue::new_with_name(1000u64, "old".to_string())
+                .with_uri("https://example.com/old"),
+        );
+
+        let new_values = [
+            KnownValue::new_with_name(2000u64, "fresh".to_string()),
+            KnownValue::new(2001),
+        ];
+        store.replace_all(new_values.clone());
+
+        assert_eq!(store, KnownValuesStore::new(new_values));
+        assert_eq!(store.codepoints_sorted(), vec![2000, 2001]);
+        assert!(store.known_value_named("isA").is_none());
+        assert!(store.known_value_named("old").is_none());
+        assert!(
+            store
+                .known_value_by_uri("https://example.com/old")
+                .is_none()
+        );
+        assert_eq!(store.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_retain_category_keeps_only_xid_privileges() {
+        let mut store = KnownValuesStore::with_builtins();
+        store.insert(KnownValue::new_with_name(1000u64, "custom".to_string()));
+        store.insert(KnownValue::new_with_name(77u64, "loaded".to_string()));
+        store.retain_category(Category::XidPrivileges);
+
+        let codepoints = store.codepoints_sorted();
+        assert_eq!(codepoints.len(), crate::XID_PRIVILEGES.len() + 1);
+        assert!(
+            codepoints
+                .iter()
+                .all(|codepoint| (70..=99).contains(codepoint))
+        );
+        assert_eq!(store.codepoint_of("loaded"), Some(77));
+        assert_eq!(store.codepoint_of("Delegate"), Some(80));
+        assert_eq!(store.codepoint_of("isA"), None);
+        assert_eq!(store.codepoint_of("custom"), None);
+        assert_eq!(store.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut store = KnownValuesStore::default();
+        store.extend_from_slice(&[crate::IS_A, crate::NOTE, crate::SIGNED]);
+
+        assert_eq!(store.codepoints_sorted(), vec![1, 3, 4]);
+        assert_eq!(store.codepoint_of("isA"), Some(1));
+        assert_eq!(store.codepoint_of("note"), Some(4));
+        assert_eq!(store.codepoint_of("signed"), Some(3));
+    }
+
+    #[test]
+    fn test_names_with_prefix_in_range() {
+        let store = KnownValuesStore::new([
+            crate::PARENT_PATH,
+            crate::PARENT_FINGERPRINT,
+            crate::PARENT,
+            crate::PARENT_CHILD_GRAPH,
+            crate::CHAIN_CODE,
+            KnownValue::new(550),
+        ]);
+
+        let codepoints = |prefix, range| {
+            store
+                .names_with_prefix_in_range(prefix, range)
+                .into_iter()
+                .map(KnownValue::value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(codepoints("parent", 500..=599), vec![503, 505]);
+        assert_eq!(codepoints("parent", 0..=u64::MAX), vec![503, 505, 704]);
+        assert_eq!(codepoints("Parent", 600..=799), vec![602]);
+        assert_eq!(codepoints("", 500..=599), vec![501, 503, 505]);
+        assert!(codepoints("parent", 504..=504).is_empty());
+        assert!(codepoints("550", 500..=599).is_empty());
+    }
+
+    #[test]
+    fn test_known_value_named_or_new() {
+        let store = KnownValuesStore::new([
+            crate::IS_A,
+            KnownValue::new_with_name(1000u64, "custom".to_string()),
+            KnownValue::new_with_name(2000u64, "3000".to_string()),
+        ]);
+
+        // A stored name wins, even if it looks like a number.
+        assert!(
+            store
+                .known_value_named_or_new("isA")
+                .is_exactly(&crate::IS_A)
+        );
+        assert_eq!(store.known_value_named_or_new("3000").value(), 2000);
+
+        // A numeric miss yields a bare value, ignoring the stored name.
+        let numeric = store.known_value_named_or_new("1000");
+        assert_eq!(numeric.value(), 1000);
+        assert_eq!(numeric.assigned_name(), None);
+        assert_eq!(store.known_value_named_or_new("42"), KnownValue::new(42));
+
+        // Anything else becomes an unresolved placeholder.
+        for name in ["unknown", "-1", "1.5", ""] {
+            let placeholder = store.known_value_named_or_new(name);
+            assert!(!placeholder.is_resolved());
+            assert_eq!(placeholder.assigned_name(), Some(name));
+        }
+    }
+
+    #[test]
+    fn test_page() {
+        let store = KnownValuesStore::new(
+            [500u64, 100, 400, 200, 700, 300, 600].map(KnownValue::new),
+        );
+
+        let mut seen = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = store.page(offset, 3);
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 3);
+            seen.extend(page.iter().map(|known_value| known_value.value()));
+            offset += page.len();
+        }
+        assert_eq!(seen, vec![100, 200, 300, 400, 500, 600, 700]);
+
+        assert_eq!(store.page(6, 3), vec![&KnownValue::new(700)]);
+        assert!(store.page(7, 3).is_empty());
+        assert!(store.page(0, 0).is_empty());
+        assert!(KnownValuesStore::default().page(0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_audit_against() {
+        let canonical = KnownValuesStore::with_builtins();
+        assert!(canonical.audit_against(&canonical).is_empty());
+
+        let mut store = canonical.clone();
+        store.insert(KnownValue::new_with_name(1u64, "type".to_string()));
+        store.insert(KnownValue::new(4));
+        store.insert(KnownValue::new_with_name(1000u64, "local".to_string()));
+
+        let findings = store.audit_against(&canonical);
+        assert_eq!(
+            findings,
+            vec![
+                AuditFinding {
+                    codepoint: 1,
+                    name: Some("type".to_string()),
+                    canonical_name: Some("isA".to_string()),
+                },
+                AuditFinding {
+                    codepoint: 4,
+                    name: None,
+                    canonical_name: Some("note".to_string()),
+                },
+            ]
+        );
+        assert_eq!(
+            findings[1].to_string(),
+            "codepoint 4 is unnamed but canonically \"note\""
+        );
+
+        // Codepoints missing from either side are ignored.
+        let partial = KnownValuesStore::new([crate::NOTE]);
+        assert!(partial.audit_against(&canonical).is_empty());
+        assert_eq!(canonical.audit_against(&store).len(), 2);
+    }
+
+    #[test]
+    fn test_require_named() {
+        let store = KnownValuesStore::new([
+            crate::IS_A,
+            KnownValue::new_with_name(1000u64, "custom".to_string()),
+        ]);
+
+        assert!(store.require_named(1).unwrap().is_exactly(&crate::IS_A));
+        assert_eq!(store.require_named(1000).unwrap().name(), "custom");
+
+        let error = store.require_named(4).unwrap_err();
+        assert_eq!(error, UnknownCodepoint { codepoint: 4 });
+        assert_eq!(error.to_string(), "unknown codepoint 4");
+
+        // A stored value without a name is not a named value
+        let mut store = store;
+        store.insert(KnownValue::new(5));
+        assert!(store.get(5).is_some());
+        assert_eq!(
+            store.require_named(5),
+            Err(UnknownCodepoint { codepoint: 5 })
+        );
+    }
+
+    #[test]
+    fn test_store_keeps_interned_names() {
+        let interned = KnownValue::interned(1000u64, "storeInterned");
+        let store = KnownValuesStore::new([interned.clone(), crate::IS_A]);
+        let other = KnownValuesStore::new([interned.clone()]);
+
+        let shared = interned.assigned_name().unwrap().as_ptr();
+        let stored = store.get(1000).unwrap();
+        assert_eq!(stored.assigned_name().unwrap().as_ptr(), shared);
+        let named = store.known_value_named("storeInterned").unwrap();
+        assert_eq!(named.assigned_name().unwrap().as_ptr(), shared);
+        assert_eq!(other.name_of(1000).unwrap().as_ptr(), shared);
+        assert!(store.validate().is_ok());
+
+        // Names that were not interned are left alone
+        let plain = KnownValue::new_with_name(1001u64, "storePlain".into());
+        let mut store = store;
+        store.insert(plain);
+        let pooled = KnownValue::interned(1001u64, "storePlain");
+        assert_ne!(
+            store.name_of(1001).unwrap().as_ptr(),
+            pooled.assigned_name().unwrap().as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_insert_if_absent_keeps_existing_names() {
+        let user = KnownValue::new_with_name(1000u64, "note".to_string());
+        let mut store = KnownValuesStore::new([user.clone()]);
+
+        // The default's codepoint is free, but its name is taken
+        assert!(!store.insert_if_absent(crate::NOTE));
+        assert!(store.get(crate::NOTE.value()).is_none());
+        assert!(store.get(1000).unwrap().is_exactly(&user));
+        assert_eq!(store.codepoint_of("note"), Some(1000));
+
+        assert!(store.insert_if_absent(crate::IS_A));
+        assert!(store.insert_if_absent(KnownValue::new(5)));
+        assert_eq!(store.codepoints_sorted(), vec![1, 5, 1000]);
+        assert_eq!(store.validate(), Ok(()));
+    }
+}
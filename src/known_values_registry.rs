@@ -1,8 +1,11 @@
-use std::sync::{Mutex, Once};
+use std::{
+    ops::RangeInclusive,
+    sync::{Mutex, Once},
+};
 
 use paste::paste;
 
-use super::KnownValuesStore;
+use super::{KnownValue, KnownValuesStore};
 
 /// A macro that declares a known value at compile time.
 ///
@@ -42,173 +45,282 @@ macro_rules! const_known_value {
     };
 }
 
+/// Declares the builtin Known Values in named groups.
+///
+/// For each group, this defines every constant with `const_known_value!` and
+/// a static slice of the group's constants. It also defines `BUILTINS`, the
+/// flat list of every builtin in declaration order, so the groups are the
+/// single source of truth for both. With the `perfect-hash` feature, it also
+/// defines `BUILTIN_NAMES`.
+macro_rules! known_value_groups {
+    ($(
+        $(#[$meta:meta])*
+        $group:ident => [
+            $(($value:literal, $const_name:ident, $name:expr)),* $(,)?
+        ]
+    ),* $(,)?) => {
+        $($(const_known_value!($value, $const_name, $name);)*)*
+
+        $(
+            $(#[$meta])*
+            pub static $group: &[KnownValue] = &[$($const_name),*];
+        )*
+
+        /// All Known Values defined in the registry, sorted by codepoint.
+        pub(crate) const BUILTINS: &[KnownValue] = &[$($($const_name),*),*];
+
+        /// A compile-time perfect hash map from the codepoint of every Known
+        /// Value defined in the registry to its name.
+        ///
+        /// Lookups are constant-time, lock-free, and allocation-free. Like
+        /// [`builtin_name`], this covers only the builtins; values loaded
+        /// from directories are only available through a store.
+        ///
+        /// This map is only available when the `perfect-hash` feature is
+        /// enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// assert_eq!(known_values::BUILTIN_NAMES.get(&1), Some(&"isA"));
+        /// assert_eq!(known_values::BUILTIN_NAMES.get(&999_999), None);
+        /// ```
+        #[cfg(feature = "perfect-hash")]
+        pub static BUILTIN_NAMES: phf::Map<u64, &'static str> = paste! {
+            phf::phf_map! { $($([<$value u64>] => $name),*),* }
+        };
+    };
+}
+
 // For definitions see: https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2023-002-known-value.md#appendix-a-registry
 
-//
-// General
-//
-
-const_known_value!(0, UNIT, "");
-const_known_value!(1, IS_A, "isA");
-const_known_value!(2, ID, "id");
-const_known_value!(3, SIGNED, "signed");
-const_known_value!(4, NOTE, "note");
-const_known_value!(5, HAS_RECIPIENT, "hasRecipient");
-const_known_value!(6, SSKR_SHARE, "sskrShare");
-const_known_value!(7, CONTROLLER, "controller");
-const_known_value!(8, KEY, "key");
-const_known_value!(9, DEREFERENCE_VIA, "dereferenceVia");
-const_known_value!(10, ENTITY, "entity");
-const_known_value!(11, NAME, "name");
-const_known_value!(12, LANGUAGE, "language");
-const_known_value!(13, ISSUER, "issuer");
-const_known_value!(14, HOLDER, "holder");
-const_known_value!(15, SALT, "salt");
-const_known_value!(16, DATE, "date");
-const_known_value!(17, UNKNOWN_VALUE, "Unknown");
-const_known_value!(18, VERSION_VALUE, "version");
-const_known_value!(19, HAS_SECRET, "hasSecret");
-const_known_value!(20, DIFF_EDITS, "edits");
-const_known_value!(21, VALID_FROM, "validFrom");
-const_known_value!(22, VALID_UNTIL, "validUntil");
-const_known_value!(23, POSITION, "position");
-const_known_value!(24, NICKNAME, "nickname");
-const_known_value!(25, VALUE, "value");
-const_known_value!(26, ATTESTATION, "attestation");
-const_known_value!(27, VERIFIABLE_AT, "verifiableAt");
-// 26-49 *unassigned*
-
-//
-// Attachments
-//
-
-const_known_value!(50, ATTACHMENT, "attachment");
-const_known_value!(51, VENDOR, "vendor");
-const_known_value!(52, CONFORMS_TO, "conformsTo");
-// 53-59 *unassigned*
-
-//
-// XID Documents
-//
-
-const_known_value!(60, ALLOW, "allow");
-const_known_value!(61, DENY, "deny");
-const_known_value!(62, ENDPOINT, "endpoint");
-const_known_value!(63, DELEGATE, "delegate");
-const_known_value!(64, PROVENANCE, "provenance");
-const_known_value!(65, PRIVATE_KEY, "privateKey");
-const_known_value!(66, SERVICE, "service");
-const_known_value!(67, CAPABILITY, "capability");
-const_known_value!(68, PROVENANCE_GENERATOR, "provenanceGenerator");
-// 68-69 *unassigned*
-
-//
-// XID Privileges
-//
-
-const_known_value!(70, PRIVILEGE_ALL, "All");
-const_known_value!(71, PRIVILEGE_AUTH, "Authorize");
-const_known_value!(72, PRIVILEGE_SIGN, "Sign");
-const_known_value!(73, PRIVILEGE_ENCRYPT, "Encrypt");
-const_known_value!(74, PRIVILEGE_ELIDE, "Elide");
-const_known_value!(75, PRIVILEGE_ISSUE, "Issue");
-const_known_value!(76, PRIVILEGE_ACCESS, "Access");
-// 77-79 *unassigned*
-const_known_value!(80, PRIVILEGE_DELEGATE, "Delegate");
-const_known_value!(81, PRIVILEGE_VERIFY, "Verify");
-const_known_value!(82, PRIVILEGE_UPDATE, "Update");
-const_known_value!(83, PRIVILEGE_TRANSFER, "Transfer");
-const_known_value!(84, PRIVILEGE_ELECT, "Elect");
-const_known_value!(85, PRIVILEGE_BURN, "Burn");
-const_known_value!(86, PRIVILEGE_REVOKE, "Revoke");
-// 87-99 *unassigned*
-
-//
-// Expression and Function Calls
-//
-
-const_known_value!(100, BODY, "body");
-const_known_value!(101, RESULT, "result");
-const_known_value!(102, ERROR, "error");
-const_known_value!(103, OK_VALUE, "OK");
-const_known_value!(104, PROCESSING_VALUE, "Processing");
-const_known_value!(105, SENDER, "sender");
-const_known_value!(106, SENDER_CONTINUATION, "senderContinuation");
-const_known_value!(107, RECIPIENT_CONTINUATION, "recipientContinuation");
-const_known_value!(108, CONTENT, "content");
-// 109-199 *unassigned*
-
-//
-// Cryptography
-//
-
-const_known_value!(200, SEED_TYPE, "Seed");
-const_known_value!(201, PRIVATE_KEY_TYPE, "PrivateKey");
-const_known_value!(202, PUBLIC_KEY_TYPE, "PublicKey");
-const_known_value!(203, MASTER_KEY_TYPE, "MasterKey");
-// 204-299 *unassigned*
-
-//
-// Cryptocurrency Assets
-//
-
-const_known_value!(300, ASSET, "asset");
-const_known_value!(301, BITCOIN_VALUE, "Bitcoin");
-const_known_value!(302, ETHEREUM_VALUE, "Ethereum");
-const_known_value!(303, TEZOS_VALUE, "Tezos");
-// 304-399 *unassigned*
-
-//
-// Cryptocurrency Networks
-//
-
-const_known_value!(400, NETWORK, "network");
-const_known_value!(401, MAIN_NET_VALUE, "MainNet");
-const_known_value!(402, TEST_NET_VALUE, "TestNet");
-// 403-499 *unassigned*
-
-//
-// Bitcoin
-//
-
-const_known_value!(500, BIP32_KEY_TYPE, "BIP32Key");
-const_known_value!(501, CHAIN_CODE, "chainCode");
-const_known_value!(502, DERIVATION_PATH_TYPE, "DerivationPath");
-const_known_value!(503, PARENT_PATH, "parentPath");
-const_known_value!(504, CHILDREN_PATH, "childrenPath");
-const_known_value!(505, PARENT_FINGERPRINT, "parentFingerprint");
-const_known_value!(506, PSBT_TYPE, "PSBT");
-const_known_value!(507, OUTPUT_DESCRIPTOR_TYPE, "OutputDescriptor");
-const_known_value!(508, OUTPUT_DESCRIPTOR, "outputDescriptor");
-// 509-599 *unassigned*
-
-//
-// Graphs
-//
-
-const_known_value!(600, GRAPH, "Graph");
-const_known_value!(601, SOURCE_TARGET_GRAPH, "SourceTargetGraph");
-const_known_value!(602, PARENT_CHILD_GRAPH, "ParentChildGraph");
-const_known_value!(603, DIGRAPH, "Digraph");
-const_known_value!(604, ACYCLIC_GRAPH, "AcyclicGraph");
-const_known_value!(605, MULTIGRAPH, "Multigraph");
-const_known_value!(606, PSEUDOGRAPH, "Pseudograph");
-const_known_value!(607, GRAPH_FRAGMENT, "GraphFragment");
-const_known_value!(608, DAG, "DAG");
-const_known_value!(609, TREE, "Tree");
-const_known_value!(610, FOREST, "Forest");
-const_known_value!(611, COMPOUND_GRAPH, "CompoundGraph");
-const_known_value!(612, HYPERGRAPH, "Hypergraph");
-const_known_value!(613, DIHYPERGRAPH, "Dihypergraph");
-// 614-699 *unassigned*
-const_known_value!(700, NODE, "node");
-const_known_value!(701, EDGE, "edge");
-const_known_value!(702, SOURCE, "source");
-const_known_value!(703, TARGET, "target");
-const_known_value!(704, PARENT, "parent");
-const_known_value!(705, CHILD, "child");
-const_known_value!(706, SELF, "Self");
-// 707-... *unassigned*
+known_value_groups! {
+    /// General-purpose Known Values, such as common predicates.
+    GENERAL => [
+        (0, UNIT, ""),
+        (1, IS_A, "isA"),
+        (2, ID, "id"),
+        (3, SIGNED, "signed"),
+        (4, NOTE, "note"),
+        (5, HAS_RECIPIENT, "hasRecipient"),
+        (6, SSKR_SHARE, "sskrShare"),
+        (7, CONTROLLER, "controller"),
+        (8, KEY, "key"),
+        (9, DEREFERENCE_VIA, "dereferenceVia"),
+        (10, ENTITY, "entity"),
+        (11, NAME, "name"),
+        (12, LANGUAGE, "language"),
+        (13, ISSUER, "issuer"),
+        (14, HOLDER, "holder"),
+        (15, SALT, "salt"),
+        (16, DATE, "date"),
+        (17, UNKNOWN_VALUE, "Unknown"),
+        (18, VERSION_VALUE, "version"),
+        (19, HAS_SECRET, "hasSecret"),
+        (20, DIFF_EDITS, "edits"),
+        (21, VALID_FROM, "validFrom"),
+        (22, VALID_UNTIL, "validUntil"),
+        (23, POSITION, "position"),
+        (24, NICKNAME, "nickname"),
+        (25, VALUE, "value"),
+        (26, ATTESTATION, "attestation"),
+        (27, VERIFIABLE_AT, "verifiableAt"),
+        // 28-49 *unassigned*
+    ],
+    /// Known Values for envelope attachments.
+    ATTACHMENTS => [
+        (50, ATTACHMENT, "attachment"),
+        (51, VENDOR, "vendor"),
+        (52, CONFORMS_TO, "conformsTo"),
+        // 53-59 *unassigned*
+    ],
+    /// Known Values for XID documents.
+    XID_DOCUMENTS => [
+        (60, ALLOW, "allow"),
+        (61, DENY, "deny"),
+        (62, ENDPOINT, "endpoint"),
+        (63, DELEGATE, "delegate"),
+        (64, PROVENANCE, "provenance"),
+        (65, PRIVATE_KEY, "privateKey"),
+        (66, SERVICE, "service"),
+        (67, CAPABILITY, "capability"),
+        (68, PROVENANCE_GENERATOR, "provenanceGenerator"),
+        // 69 *unassigned*
+    ],
+    /// Known Values for XID privileges.
+    XID_PRIVILEGES => [
+        (70, PRIVILEGE_ALL, "All"),
+        (71, PRIVILEGE_AUTH, "Authorize"),
+        (72, PRIVILEGE_SIGN, "Sign"),
+        (73, PRIVILEGE_ENCRYPT, "Encrypt"),
+        (74, PRIVILEGE_ELIDE, "Elide"),
+        (75, PRIVILEGE_ISSUE, "Issue"),
+        (76, PRIVILEGE_ACCESS, "Access"),
+        // 77-79 *unassigned*
+        (80, PRIVILEGE_DELEGATE, "Delegate"),
+        (81, PRIVILEGE_VERIFY, "Verify"),
+        (82, PRIVILEGE_UPDATE, "Update"),
+        (83, PRIVILEGE_TRANSFER, "Transfer"),
+        (84, PRIVILEGE_ELECT, "Elect"),
+        (85, PRIVILEGE_BURN, "Burn"),
+        (86, PRIVILEGE_REVOKE, "Revoke"),
+        // 87-99 *unassigned*
+    ],
+    /// Known Values for expressions and function calls.
+    EXPRESSIONS => [
+        (100, BODY, "body"),
+        (101, RESULT, "result"),
+        (102, ERROR, "error"),
+        (103, OK_VALUE, "OK"),
+        (104, PROCESSING_VALUE, "Processing"),
+        (105, SENDER, "sender"),
+        (106, SENDER_CONTINUATION, "senderContinuation"),
+        (107, RECIPIENT_CONTINUATION, "recipientContinuation"),
+        (108, CONTENT, "content"),
+        // 109-199 *unassigned*
+    ],
+    /// Known Values for cryptographic types.
+    CRYPTOGRAPHY => [
+        (200, SEED_TYPE, "Seed"),
+        (201, PRIVATE_KEY_TYPE, "PrivateKey"),
+        (202, PUBLIC_KEY_TYPE, "PublicKey"),
+        (203, MASTER_KEY_TYPE, "MasterKey"),
+        // 204-299 *unassigned*
+    ],
+    /// Known Values for cryptocurrency assets.
+    CRYPTOCURRENCY_ASSETS => [
+        (300, ASSET, "asset"),
+        (301, BITCOIN_VALUE, "Bitcoin"),
+        (302, ETHEREUM_VALUE, "Ethereum"),
+        (303, TEZOS_VALUE, "Tezos"),
+        // 304-399 *unassigned*
+    ],
+    /// Known Values for cryptocurrency networks.
+    CRYPTOCURRENCY_NETWORKS => [
+        (400, NETWORK, "network"),
+        (401, MAIN_NET_VALUE, "MainNet"),
+        (402, TEST_NET_VALUE, "TestNet"),
+        // 403-499 *unassigned*
+    ],
+    /// Known Values for Bitcoin types.
+    BITCOIN => [
+        (500, BIP32_KEY_TYPE, "BIP32Key"),
+        (501, CHAIN_CODE, "chainCode"),
+        (502, DERIVATION_PATH_TYPE, "DerivationPath"),
+        (503, PARENT_PATH, "parentPath"),
+        (504, CHILDREN_PATH, "childrenPath"),
+        (505, PARENT_FINGERPRINT, "parentFingerprint"),
+        (506, PSBT_TYPE, "PSBT"),
+        (507, OUTPUT_DESCRIPTOR_TYPE, "OutputDescriptor"),
+        (508, OUTPUT_DESCRIPTOR, "outputDescriptor"),
+        // 509-599 *unassigned*
+    ],
+    /// Known Values for graphs.
+    GRAPHS => [
+        (600, GRAPH, "Graph"),
+        (601, SOURCE_TARGET_GRAPH, "SourceTargetGraph"),
+        (602, PARENT_CHILD_GRAPH, "ParentChildGraph"),
+        (603, DIGRAPH, "Digraph"),
+        (604, ACYCLIC_GRAPH, "AcyclicGraph"),
+        (605, MULTIGRAPH, "Multigraph"),
+        (606, PSEUDOGRAPH, "Pseudograph"),
+        (607, GRAPH_FRAGMENT, "GraphFragment"),
+        (608, DAG, "DAG"),
+        (609, TREE, "Tree"),
+        (610, FOREST, "Forest"),
+        (611, COMPOUND_GRAPH, "CompoundGraph"),
+        (612, HYPERGRAPH, "Hypergraph"),
+        (613, DIHYPERGRAPH, "Dihypergraph"),
+        // 614-699 *unassigned*
+        (700, NODE, "node"),
+        (701, EDGE, "edge"),
+        (702, SOURCE, "source"),
+        (703, TARGET, "target"),
+        (704, PARENT, "parent"),
+        (705, CHILD, "child"),
+        (706, SELF, "Self"),
+        // 707-... *unassigned*
+    ],
+}
+
+/// A sorted table of `(codepoint, name)` pairs for every Known Value defined
+/// in the registry.
+///
+/// This table requires no heap allocation or global initialization, making it
+/// suitable for embedded use. See [`builtin_name`] for lookups.
+pub static BUILTIN_TABLE: &[(u64, &str)] = &{
+    let mut table = [(0, ""); BUILTINS.len()];
+    let mut i = 0;
+    while i < BUILTINS.len() {
+        let Some(name) = BUILTINS[i].static_name() else {
+            panic!("builtin Known Values must have static names");
+        };
+        table[i] = (BUILTINS[i].value(), name);
+        assert!(
+            i == 0 || table[i - 1].0 < table[i].0,
+            "builtin Known Values must be sorted by codepoint"
+        );
+        i += 1;
+    }
+    table
+};
+
+/// Returns the name of a builtin Known Value without touching the global
+/// registry.
+///
+/// This performs a binary search over [`BUILTIN_TABLE`] and is usable in
+/// `const` contexts. Values loaded from directories are not consulted. With
+/// the `perfect-hash` feature, `BUILTIN_NAMES` offers constant-time lookups
+/// outside `const` contexts.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::builtin_name;
+///
+/// assert_eq!(builtin_name(1), Some("isA"));
+/// assert_eq!(builtin_name(999_999), None);
+///
+/// const NOTE_NAME: Option<&str> = builtin_name(4);
+/// assert_eq!(NOTE_NAME, Some("note"));
+/// ```
+pub const fn builtin_name(value: u64) -> Option<&'static str> {
+    let mut low = 0;
+    let mut high = BUILTIN_TABLE.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let (codepoint, name) = BUILTIN_TABLE[mid];
+        if codepoint == value {
+            return Some(name);
+        } else if codepoint < value {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    None
+}
+
+/// Returns the contiguous ranges of unassigned codepoints between the lowest
+/// and highest builtin Known Values, in ascending order.
+///
+/// Codepoints above the highest builtin are not reported. Values loaded from
+/// directories are not consulted.
+///
+/// # Examples
+///
+/// ```
+/// let gaps = known_values::unassigned_gaps();
+/// assert!(gaps.contains(&(53..=59)));
+/// assert_eq!(gaps.first(), Some(&(28..=49)));
+/// ```
+pub fn unassigned_gaps() -> Vec<RangeInclusive<u64>> {
+    BUILTIN_TABLE
+        .windows(2)
+        .filter(|pair| pair[1].0 - pair[0].0 > 1)
+        .map(|pair| pair[0].0 + 1..=pair[1].0 - 1)
+        .collect()
+}
 
 /// A lazily initialized singleton that holds the global registry of known
 /// values.
@@ -231,6 +343,10 @@ const_known_value!(706, SELF, "Self");
 pub struct LazyKnownValues {
     init: Once,
     data: Mutex<Option<KnownValuesStore>>,
+    /// Serializes reloads, so a reload that read an older configuration
+    /// cannot swap its store in after one that read a newer configuration.
+    #[cfg(feature = "directory-loading")]
+    reload_lock: Mutex<()>,
 }
 
 impl LazyKnownValues {
@@ -248,7 +364,203 @@ impl LazyKnownValues {
     pub fn get(&self) -> std::sync::MutexGuard<'_, Option<KnownValuesStore>> {
         self.init.call_once(|| {
             #[allow(unused_mut)]
-            let mut m = KnownValuesStore::new([
+            let mut m = KnownValuesStore::with_builtins();
+
+            // When directory-loading feature is enabled, load additional values
+            // from configured directories. Values from directories override
+            // hardcoded values when codepoints match.
+            #[cfg(feature = "directory-loading")]
+            {
+                let config = crate::directory_loader::get_and_lock_config();
+                let result = crate::directory_loader::load_from_config(&config);
+                insert_loaded_values(&mut m, &result);
+            }
+
+            *self.data.lock().unwrap() = Some(m);
+        });
+        self.data.lock().unwrap()
+    }
+
+    /// Rebuilds the store from the hardcoded values and the current
+    /// directory configuration, then swaps it in under the lock.
+    ///
+    /// The new store is built without holding the lock, so readers see
+    /// either the old store or the new one, never a partial state. If the
+    /// load reports more errors than the configuration tolerates, the old
+    /// store is kept.
+    ///
+    /// Concurrent reloads run one at a time, from reading the configuration
+    /// through the swap, so the last reload to start is the last to finish.
+    #[cfg(feature = "directory-loading")]
+    pub(crate) fn reload(
+        &self,
+    ) -> Result<crate::LoadResult, crate::ReloadError> {
+        // Make sure the initial load has run, so it cannot later overwrite
+        // the reloaded store.
+        drop(self.get());
+
+        let _reloading = self
+            .reload_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let config = crate::directory_loader::get_and_lock_config();
+        let result = crate::directory_loader::load_from_config(&config);
+        let max_errors = config.get_max_reload_errors();
+        if result.errors.len() > max_errors {
+            return Err(crate::ReloadError { result, max_errors });
+        }
+
+        let mut m = KnownValuesStore::with_builtins();
+        insert_loaded_values(&mut m, &result);
+        *self.data.lock().unwrap() = Some(m);
+        Ok(result)
+    }
+}
+
+/// Inserts the values of a directory load into `store`, overriding
+/// hardcoded values when codepoints match.
+///
+/// Values are inserted in ascending codepoint order, so when loaded values
+/// share a name, the highest codepoint keeps it regardless of the iteration
+/// order of [`LoadResult::values`](crate::LoadResult::values).
+#[cfg(feature = "directory-loading")]
+fn insert_loaded_values(
+    store: &mut KnownValuesStore,
+    result: &crate::LoadResult,
+) {
+    let mut values: Vec<_> = result.values_iter().collect();
+    values.sort_by_key(|value| value.value());
+    for value in values {
+        store.insert(value.clone());
+    }
+}
+
+/// The global registry of Known Values.
+///
+/// This static instance provides access to all standard Known Values defined in
+/// the registry specification. It is lazily initialized on first access.
+///
+/// The store holds every builtin constant, including [`VALUE`] (25) and
+/// [`SELF`] (706). Releases before the builtins were generated from a single
+/// list left those two out of the store, so their names did not resolve
+/// through it.
+///
+/// Most users should not need to interact with this directly, as the predefined
+/// Known Values are exposed as constants in the `known_values` module.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::*;
+///
+/// // Access the global store
+/// let binding = KNOWN_VALUES.get();
+/// let known_values = binding.as_ref().unwrap();
+///
+/// // Look up a Known Value by name
+/// let is_a = known_values.known_value_named("isA").unwrap();
+/// assert_eq!(is_a.value(), 1);
+/// ```
+pub static KNOWN_VALUES: LazyKnownValues = LazyKnownValues {
+    init: Once::new(),
+    data: Mutex::new(None),
+    #[cfg(feature = "directory-loading")]
+    reload_lock: Mutex::new(()),
+};
+
+/// Looks up a Known Value by name in the global registry.
+///
+/// This locks [`KNOWN_VALUES`] only for the duration of the lookup and
+/// returns an owned value, so callers never hold the guard.
+///
+/// # Examples
+///
+/// ```
+/// let is_a = known_values::lookup_name("isA").unwrap();
+/// assert_eq!(is_a.value(), 1);
+///
+/// assert!(known_values::lookup_name("notARegisteredName").is_none());
+/// ```
+pub fn lookup_name(name: &str) -> Option<KnownValue> {
+    KnownValuesStore::known_value_for_name(name, KNOWN_VALUES.get().as_ref())
+}
+
+/// Looks up a Known Value by codepoint in the global registry.
+///
+/// Codepoints that are not in the registry yield a KnownValue with no
+/// assigned name. Like [`lookup_name`], the lock on [`KNOWN_VALUES`] is
+/// released before returning.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(known_values::lookup_codepoint(4).name(), "note");
+///
+/// let unknown = known_values::lookup_codepoint(999_999_999);
+/// assert_eq!(unknown.assigned_name(), None);
+/// ```
+pub fn lookup_codepoint(value: u64) -> KnownValue {
+    KnownValuesStore::known_value_for_raw_value(
+        value,
+        KNOWN_VALUES.get().as_ref(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_1() {
+        assert_eq!(crate::IS_A.value(), 1);
+        assert_eq!(crate::IS_A.name(), "isA");
+        let binding = crate::KNOWN_VALUES.get();
+        let known_values = binding.as_ref().unwrap();
+        assert_eq!(known_values.known_value_named("isA").unwrap().value(), 1);
+    }
+
+    #[test]
+    fn test_builtin_name_agrees_with_store() {
+        let binding = crate::KNOWN_VALUES.get();
+        let known_values = binding.as_ref().unwrap();
+        for &(codepoint, name) in crate::BUILTIN_TABLE {
+            assert_eq!(crate::builtin_name(codepoint), Some(name));
+            assert_eq!(
+                known_values.assigned_name(&crate::KnownValue::new(codepoint)),
+                Some(name)
+            );
+        }
+        assert_eq!(crate::builtin_name(999_999), None);
+    }
+
+    #[test]
+    fn test_global_store_includes_value_and_self() {
+        let binding = crate::KNOWN_VALUES.get();
+        let known_values = binding.as_ref().unwrap();
+        assert_eq!(known_values.name_of(25), Some("value"));
+        assert_eq!(known_values.name_of(706), Some("Self"));
+        for builtin in super::BUILTINS {
+            assert!(known_values.get(builtin.value()).is_some());
+        }
+    }
+
+    #[cfg(feature = "perfect-hash")]
+    #[test]
+    fn test_builtin_names_agree_with_store() {
+        let store = crate::KnownValuesStore::with_builtins();
+        assert_eq!(crate::BUILTIN_NAMES.len(), crate::BUILTIN_TABLE.len());
+        for &(codepoint, name) in crate::BUILTIN_TABLE {
+            assert_eq!(crate::BUILTIN_NAMES.get(&codepoint), Some(&name));
+            assert_eq!(store.name_of(codepoint), Some(name));
+        }
+        assert_eq!(crate::BUILTIN_NAMES.get(&999_999), None);
+    }
+
+    #[test]
+    fn test_group_slices() {
+        use crate::*;
+
+        assert_eq!(
+            GENERAL,
+            &[
                 UNIT,
                 IS_A,
                 ID,
@@ -274,11 +586,15 @@ impl LazyKnownValues {
                 VALID_UNTIL,
                 POSITION,
                 NICKNAME,
+                VALUE,
                 ATTESTATION,
                 VERIFIABLE_AT,
-                ATTACHMENT,
-                VENDOR,
-                CONFORMS_TO,
+            ]
+        );
+        assert_eq!(ATTACHMENTS, &[ATTACHMENT, VENDOR, CONFORMS_TO,]);
+        assert_eq!(
+            XID_DOCUMENTS,
+            &[
                 ALLOW,
                 DENY,
                 ENDPOINT,
@@ -288,6 +604,11 @@ impl LazyKnownValues {
                 SERVICE,
                 CAPABILITY,
                 PROVENANCE_GENERATOR,
+            ]
+        );
+        assert_eq!(
+            XID_PRIVILEGES,
+            &[
                 PRIVILEGE_ALL,
                 PRIVILEGE_AUTH,
                 PRIVILEGE_SIGN,
@@ -302,6 +623,11 @@ impl LazyKnownValues {
                 PRIVILEGE_ELECT,
                 PRIVILEGE_BURN,
                 PRIVILEGE_REVOKE,
+            ]
+        );
+        assert_eq!(
+            EXPRESSIONS,
+            &[
                 BODY,
                 RESULT,
                 ERROR,
@@ -311,17 +637,28 @@ impl LazyKnownValues {
                 SENDER_CONTINUATION,
                 RECIPIENT_CONTINUATION,
                 CONTENT,
+            ]
+        );
+        assert_eq!(
+            CRYPTOGRAPHY,
+            &[
                 SEED_TYPE,
                 PRIVATE_KEY_TYPE,
                 PUBLIC_KEY_TYPE,
                 MASTER_KEY_TYPE,
-                ASSET,
-                BITCOIN_VALUE,
-                ETHEREUM_VALUE,
-                TEZOS_VALUE,
-                NETWORK,
-                MAIN_NET_VALUE,
-                TEST_NET_VALUE,
+            ]
+        );
+        assert_eq!(
+            CRYPTOCURRENCY_ASSETS,
+            &[ASSET, BITCOIN_VALUE, ETHEREUM_VALUE, TEZOS_VALUE,]
+        );
+        assert_eq!(
+            CRYPTOCURRENCY_NETWORKS,
+            &[NETWORK, MAIN_NET_VALUE, TEST_NET_VALUE,]
+        );
+        assert_eq!(
+            BITCOIN,
+            &[
                 BIP32_KEY_TYPE,
                 CHAIN_CODE,
                 DERIVATION_PATH_TYPE,
@@ -331,6 +668,11 @@ impl LazyKnownValues {
                 PSBT_TYPE,
                 OUTPUT_DESCRIPTOR_TYPE,
                 OUTPUT_DESCRIPTOR,
+            ]
+        );
+        assert_eq!(
+            GRAPHS,
+            &[
                 GRAPH,
                 SOURCE_TARGET_GRAPH,
                 PARENT_CHILD_GRAPH,
@@ -351,58 +693,46 @@ impl LazyKnownValues {
                 TARGET,
                 PARENT,
                 CHILD,
-            ]);
+                SELF,
+            ]
+        );
+    }
 
-            // When directory-loading feature is enabled, load additional values
-            // from configured directories. Values from directories override
-            // hardcoded values when codepoints match.
-            #[cfg(feature = "directory-loading")]
-            {
-                let config = crate::directory_loader::get_and_lock_config();
-                let result = crate::directory_loader::load_from_config(&config);
-                for value in result.into_values() {
-                    m.insert(value);
-                }
+    #[test]
+    fn test_unassigned_gaps() {
+        let gaps = crate::unassigned_gaps();
+        assert!(gaps.contains(&(28..=49)));
+        assert!(gaps.contains(&(69..=69)));
+        assert!(gaps.contains(&(614..=699)));
+        for gap in &gaps {
+            for codepoint in gap.clone() {
+                assert_eq!(crate::builtin_name(codepoint), None);
             }
-
-            *self.data.lock().unwrap() = Some(m);
-        });
-        self.data.lock().unwrap()
+        }
+        let unassigned: u64 =
+            gaps.iter().map(|gap| gap.end() - gap.start() + 1).sum();
+        let span = crate::BUILTIN_TABLE.last().unwrap().0
+            - crate::BUILTIN_TABLE[0].0
+            + 1;
+        assert_eq!(unassigned + crate::BUILTIN_TABLE.len() as u64, span);
     }
-}
-
-/// The global registry of Known Values.
-///
-/// This static instance provides access to all standard Known Values defined in
-/// the registry specification. It is lazily initialized on first access.
-///
-/// Most users should not need to interact with this directly, as the predefined
-/// Known Values are exposed as constants in the `known_values` module.
-///
-/// # Examples
-///
-/// ```
-/// use known_values::*;
-///
-/// // Access the global store
-/// let binding = KNOWN_VALUES.get();
-/// let known_values = binding.as_ref().unwrap();
-///
-/// // Look up a Known Value by name
-/// let is_a = known_values.known_value_named("isA").unwrap();
-/// assert_eq!(is_a.value(), 1);
-/// ```
-pub static KNOWN_VALUES: LazyKnownValues =
-    LazyKnownValues { init: Once::new(), data: Mutex::new(None) };
 
-#[cfg(test)]
-mod tests {
+    #[cfg(feature = "directory-loading")]
     #[test]
-    fn test_1() {
-        assert_eq!(crate::IS_A.value(), 1);
-        assert_eq!(crate::IS_A.name(), "isA");
-        let binding = crate::KNOWN_VALUES.get();
-        let known_values = binding.as_ref().unwrap();
-        assert_eq!(known_values.known_value_named("isA").unwrap().value(), 1);
+    fn test_insert_loaded_values_in_codepoint_order() {
+        let name = "sharedLoadedName";
+        let mut result = crate::LoadResult::default();
+        for codepoint in (90_000u64..90_064).rev() {
+            result.values.insert(
+                codepoint,
+                crate::KnownValue::new_with_name(codepoint, name.to_string()),
+            );
+        }
+
+        let mut store = crate::KnownValuesStore::default();
+        super::insert_loaded_values(&mut store, &result);
+        assert_eq!(store.codepoint_of(name), Some(90_063));
+        assert_eq!(store.name_of(90_000), None);
+        assert_eq!(store.validate(), Ok(()));
     }
 }
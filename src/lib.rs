@@ -24,6 +24,8 @@
 //! assert_eq!(store.known_value_named("isA").unwrap().value(), 1);
 //! ```
 //!
+//! The [`prelude`] module re-exports the most commonly used items.
+//!
 //! # Directory Loading Feature
 //!
 //! When the `directory-loading` feature is enabled (default), this crate can
@@ -49,6 +51,25 @@
 //! }
 //! ```
 //!
+//! A bare top-level array of entries is also accepted:
+//!
+//! ```json
+//! [{"codepoint": 1000, "name": "myValue", "type": "property"}]
+//! ```
+//!
+//! With the optional `jsonc` feature, registry files may also contain `//`
+//! line comments and `/* */` block comments.
+//! Trailing commas are accepted when `DirectoryConfig::lenient_json` is
+//! enabled.
+//!
+//! Registry files may carry an optional `$schema` reference. With the optional
+//! `jsonschema` feature, each file is validated against the bundled registry
+//! schema (`REGISTRY_SCHEMA`) and mismatches are reported as
+//! `LoadError::SchemaViolation`.
+//!
+//! With the optional `archive` feature, registry files bundled into a single
+//! zip archive can be loaded with `load_from_archive`.
+//!
 //! ## Custom Configuration
 //!
 //! Configure search paths before first access (requires `directory-loading`
@@ -73,23 +94,54 @@
 //! known-values = { version = "0.15", default-features = false }
 //! ```
 //!
+//! # Perfect Hash Feature
+//!
+//! With the optional `perfect-hash` feature, `BUILTIN_NAMES` maps the
+//! codepoint of every builtin Known Value to its name through a compile-time
+//! perfect hash, for hot paths that must not lock the global registry.
+//!
 //! [bcr]: https://github.com/BlockchainCommons/Research/blob/master/papers/bcr-2023-002-known-value.md
 
 mod known_value;
-pub use known_value::KnownValue;
+pub use known_value::{CodepointOutOfRange, KnownValue, MatchOptions};
+
+mod category;
+pub use category::Category;
+
+mod as_known_value;
+pub use as_known_value::AsKnownValue;
 
 mod known_value_store;
-pub use known_value_store::KnownValuesStore;
+pub use known_value_store::{
+    AuditFinding, KnownValuesStore, StoreInconsistency, UnknownCodepoint,
+};
+
+mod overlay_store;
+pub use overlay_store::OverlayStore;
+
+mod store_diff;
+pub use store_diff::StoreDiff;
 
 mod known_values_registry;
 pub use known_values_registry::*;
 
+pub mod prelude;
+
 #[cfg(feature = "directory-loading")]
 mod directory_loader;
 
 #[cfg(feature = "directory-loading")]
 pub use directory_loader::{
-    ConfigError, DirectoryConfig, LoadError, LoadResult, RegistryEntry,
-    RegistryFile, add_search_paths, load_from_config, load_from_directory,
-    set_directory_config,
+    ConfigError, DedupStrategy, DirectoryConfig, DirectoryConfigBuilder,
+    EntryError, LoadError, LoadResult, MergeStrategy, MergeSummary,
+    NameCollision, OntologyInfo, RegistryEntry, RegistryFile, ReloadError,
+    SortOrder, ValueOverride, add_search_paths, force_set_directory_config,
+    is_initialized, load_from_config, load_from_directory, reload_known_values,
+    set_directory_config, try_load_from_config,
 };
+
+#[cfg(feature = "jsonschema")]
+pub use directory_loader::REGISTRY_SCHEMA;
+
+#[cfg(feature = "archive")]
+pub use directory_loader::load_from_archive;
@@ -0,0 +1,99 @@
+use super::{known_value::KnownValue, known_value_store::KnownValuesStore};
+
+/// A layered, read-only view over a borrowed [`KnownValuesStore`].
+///
+/// An `OverlayStore` is produced by [`KnownValuesStore::overlay`]. Lookups are
+/// answered from the overlay layer first and fall back to the base store by
+/// reference, so the base is never copied. This makes it cheap to create many
+/// short-lived override layers over one shared registry.
+///
+/// A codepoint defined in the overlay shadows the base entirely: if the
+/// overlay renames a codepoint, the base's name for it no longer resolves.
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{KnownValue, KnownValuesStore};
+///
+/// let base = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+/// let view = base.overlay(KnownValuesStore::new([
+///     KnownValue::new_with_name(1u64, "type".to_string()),
+///     KnownValue::new_with_name(100u64, "custom".to_string()),
+/// ]));
+///
+/// assert_eq!(view.known_value_named("type").unwrap().value(), 1);
+/// assert_eq!(view.known_value_named("custom").unwrap().value(), 100);
+/// assert_eq!(view.known_value_named("note").unwrap().value(), 4);
+/// assert!(view.known_value_named("isA").is_none());
+///
+/// assert_eq!(view.name(known_values::IS_A), "type");
+/// assert_eq!(view.get(4).unwrap().name(), "note");
+/// assert!(view.get(999).is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct OverlayStore<'a> {
+    base: &'a KnownValuesStore,
+    overlay: KnownValuesStore,
+}
+
+impl<'a> OverlayStore<'a> {
+    pub(crate) fn new(
+        base: &'a KnownValuesStore,
+        overlay: KnownValuesStore,
+    ) -> Self {
+        Self { base, overlay }
+    }
+
+    /// Returns the base store this view falls back to.
+    pub fn base(&self) -> &'a KnownValuesStore { self.base }
+
+    /// Returns the overlay layer consulted before the base.
+    pub fn layer(&self) -> &KnownValuesStore { &self.overlay }
+
+    /// Inserts a KnownValue into the overlay layer, leaving the base
+    /// untouched.
+    pub fn insert(&mut self, known_value: KnownValue) {
+        self.overlay.insert(known_value);
+    }
+
+    /// Looks up a KnownValue by its codepoint, preferring the overlay.
+    pub fn get(&self, codepoint: u64) -> Option<&KnownValue> {
+        self.overlay
+            .get(codepoint)
+            .or_else(|| self.base.get(codepoint))
+    }
+
+    /// Returns the assigned name for a KnownValue, if present in either
+    /// layer.
+    pub fn assigned_name(&self, known_value: &KnownValue) -> Option<&str> {
+        self.get(known_value.value())
+            .and_then(|known_value| known_value.assigned_name())
+    }
+
+    /// Returns a human-readable name for a KnownValue.
+    ///
+    /// Falls back to the KnownValue's default name if neither layer assigns
+    /// one.
+    pub fn name(&self, known_value: KnownValue) -> String {
+        self.assigned_name(&known_value)
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| known_value.name())
+    }
+
+    /// Looks up a KnownValue by its assigned name.
+    ///
+    /// Base entries whose codepoint is shadowed by the overlay are not
+    /// returned.
+    pub fn known_value_named(
+        &self,
+        assigned_name: &str,
+    ) -> Option<&KnownValue> {
+        self.overlay.known_value_named(assigned_name).or_else(|| {
+            self.base
+                .known_value_named(assigned_name)
+                .filter(|known_value| {
+                    self.overlay.get(known_value.value()).is_none()
+                })
+        })
+    }
+}
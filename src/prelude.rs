@@ -0,0 +1,22 @@
+//! Common imports for working with Known Values.
+//!
+//! `use known_values::prelude::*;` brings in the core types, the
+//! [`AsKnownValue`] conversion trait, the global registry and its lookup
+//! functions, and the most frequently used predicate constants. Items are
+//! only ever added to the prelude, never removed.
+//!
+//! # Examples
+//!
+//! ```
+//! use known_values::prelude::*;
+//!
+//! let store = KnownValuesStore::new([IS_A, NOTE]);
+//! assert_eq!("note".as_known_value(Some(&store)), Some(NOTE));
+//! assert_eq!(lookup_codepoint(IS_A.value()).name(), "isA");
+//! ```
+
+pub use crate::{
+    AsKnownValue, DATE, HAS_RECIPIENT, ID, IS_A, KNOWN_VALUES, KnownValue,
+    KnownValuesStore, NAME, NOTE, SALT, SIGNED, VALUE, lookup_codepoint,
+    lookup_name,
+};
@@ -0,0 +1,52 @@
+use super::known_value::KnownValue;
+
+/// The differences between two [`KnownValuesStore`]s.
+///
+/// A `StoreDiff` is produced by [`KnownValuesStore::diff`] and describes how
+/// to turn one store into another. It can be reviewed before being replayed
+/// with [`KnownValuesStore::apply_diff`], which supports a migration workflow
+/// between an old and a new registry.
+///
+/// All lists are sorted by codepoint.
+///
+/// [`KnownValuesStore`]: crate::KnownValuesStore
+/// [`KnownValuesStore::diff`]: crate::KnownValuesStore::diff
+/// [`KnownValuesStore::apply_diff`]: crate::KnownValuesStore::apply_diff
+///
+/// # Examples
+///
+/// ```
+/// use known_values::{KnownValue, KnownValuesStore};
+///
+/// let old = KnownValuesStore::new([known_values::IS_A, known_values::NOTE]);
+/// let new = KnownValuesStore::new([
+///     KnownValue::new_with_name(1u64, "type".to_string()),
+///     known_values::SIGNED,
+/// ]);
+///
+/// let diff = old.diff(&new);
+/// assert_eq!(diff.added, vec![known_values::SIGNED]);
+/// assert_eq!(diff.removed, vec![known_values::NOTE]);
+/// assert_eq!(diff.renamed.len(), 1);
+/// assert_eq!(diff.renamed[0].0.name(), "isA");
+/// assert_eq!(diff.renamed[0].1.name(), "type");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StoreDiff {
+    /// Values whose codepoints are only present in the other store.
+    pub added: Vec<KnownValue>,
+    /// Values whose codepoints are only present in this store.
+    pub removed: Vec<KnownValue>,
+    /// Values present in both stores under different assigned names, as
+    /// `(old, new)` pairs.
+    pub renamed: Vec<(KnownValue, KnownValue)>,
+}
+
+impl StoreDiff {
+    /// Returns true if the two stores have identical contents.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.renamed.is_empty()
+    }
+}
@@ -2,13 +2,17 @@
 
 #[cfg(feature = "directory-loading")]
 mod tests {
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     use known_values::{
-        DirectoryConfig, IS_A, KNOWN_VALUES, KnownValuesStore, NOTE,
+        ConfigError, DirectoryConfig, IS_A, KNOWN_VALUES, KnownValue,
+        KnownValuesStore, LoadError, MergeStrategy, NOTE,
     };
     use tempfile::TempDir;
 
+    /// Serializes tests that reconfigure and reload the global registry.
+    static GLOBAL_RELOAD: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
     #[test]
     fn test_global_registry_still_works() {
         // Verify KNOWN_VALUES still works with feature enabled
@@ -21,6 +25,16 @@ mod tests {
         assert_eq!(is_a.unwrap().value(), 1);
     }
 
+    #[test]
+    fn test_is_initialized_after_access() {
+        drop(KNOWN_VALUES.get());
+        assert!(known_values::is_initialized());
+        assert_eq!(
+            known_values::set_directory_config(DirectoryConfig::new()),
+            Err(known_values::ConfigError::AlreadyInitialized)
+        );
+    }
+
     #[test]
     fn test_load_from_temp_directory() {
         let temp_dir = TempDir::new().unwrap();
@@ -168,6 +182,35 @@ mod tests {
         assert!(store.known_value_named("firstVersion").is_none());
     }
 
+    #[test]
+    fn test_prepended_directory_with_merge_strategies() {
+        let base_dir = TempDir::new().unwrap();
+        let prepended_dir = TempDir::new().unwrap();
+        std::fs::write(
+            base_dir.path().join("base.json"),
+            r#"{"entries": [{"codepoint": 30001, "name": "baseVersion"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            prepended_dir.path().join("prepended.json"),
+            r#"{"entries": [{"codepoint": 30001, "name": "prependedVersion"}]}"#,
+        )
+        .unwrap();
+
+        let mut config =
+            DirectoryConfig::with_paths(vec![base_dir.path().to_path_buf()]);
+        config.prepend_path(prepended_dir.path().to_path_buf());
+
+        // Under last-wins, the prepended path loses to later ones
+        let result = known_values::load_from_config(&config);
+        assert_eq!(result.values[&30001].name(), "baseVersion");
+
+        // Under first-wins, the prepended path takes precedence
+        let config = config.merge_strategy(MergeStrategy::FirstWins);
+        let result = known_values::load_from_config(&config);
+        assert_eq!(result.values[&30001].name(), "prependedVersion");
+    }
+
     #[test]
     fn test_nonexistent_directory_is_ok() {
         let mut store = KnownValuesStore::default();
@@ -325,4 +368,1059 @@ mod tests {
         assert_eq!(count, 1);
         assert!(store.known_value_named("jsonValue").is_some());
     }
+
+    #[test]
+    fn test_bare_array_matches_enveloped_registry() {
+        let enveloped_dir = TempDir::new().unwrap();
+        std::fs::write(
+            enveloped_dir.path().join("registry.json"),
+            r#"{"entries": [
+                {"codepoint": 71001, "name": "arrayFirst"},
+                {"codepoint": 71002, "name": "arraySecond"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let bare_dir = TempDir::new().unwrap();
+        std::fs::write(
+            bare_dir.path().join("registry.json"),
+            r#"[
+                {"codepoint": 71001, "name": "arrayFirst"},
+                {"codepoint": 71002, "name": "arraySecond"}
+            ]"#,
+        )
+        .unwrap();
+
+        let config = DirectoryConfig::with_paths(vec![
+            enveloped_dir.path().to_path_buf(),
+        ]);
+        let enveloped = known_values::load_from_config(&config);
+        let config =
+            DirectoryConfig::with_paths(vec![bare_dir.path().to_path_buf()]);
+        let bare = known_values::load_from_config(&config);
+
+        assert!(!enveloped.has_errors());
+        assert!(!bare.has_errors());
+        assert_eq!(enveloped.values_count(), 2);
+        assert_eq!(bare.values_count(), 2);
+        for (codepoint, value) in &enveloped.values {
+            assert_eq!(bare.values[codepoint].name(), value.name());
+        }
+    }
+
+    #[test]
+    fn test_loaded_value_carries_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("metadata.json"),
+            r#"{"entries": [{
+                "codepoint": 72001,
+                "name": "withMetadata",
+                "type": "class",
+                "uri": "https://example.com/vocab#withMetadata",
+                "description": "A value with all metadata fields"
+            }]}"#,
+        )
+        .unwrap();
+
+        let mut store = KnownValuesStore::default();
+        store.load_from_directory(temp_dir.path()).unwrap();
+
+        let value = store.known_value_named("withMetadata").unwrap();
+        assert_eq!(value.entry_type(), Some("class"));
+        assert_eq!(value.uri(), Some("https://example.com/vocab#withMetadata"));
+        assert_eq!(
+            value.description(),
+            Some("A value with all metadata fields")
+        );
+
+        assert_eq!(IS_A.entry_type(), None);
+        assert_eq!(IS_A.uri(), None);
+        assert_eq!(IS_A.description(), None);
+    }
+
+    #[test]
+    fn test_fail_fast_and_tolerant_on_same_input() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("valid.json"),
+            r#"{"entries": [{"codepoint": 73001, "name": "failFastValid"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("invalid.json"), "{ invalid }")
+            .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+
+        // Tolerant loading collects the error and keeps going
+        let result = known_values::try_load_from_config(&config).unwrap();
+        assert!(result.has_errors());
+        assert!(result.values.contains_key(&73001));
+
+        // Fail-fast loading returns the error
+        let config = config.fail_fast(true);
+        let error = known_values::try_load_from_config(&config).unwrap_err();
+        assert!(matches!(error, LoadError::Json { .. }));
+
+        // `load_from_config` stays tolerant regardless of the setting
+        assert!(known_values::load_from_config(&config).has_errors());
+    }
+
+    #[test]
+    fn test_skip_deprecated_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("deprecated.json"),
+            r#"{"entries": [
+                {"codepoint": 75001, "name": "currentValue"},
+                {"codepoint": 75002, "name": "oldValue", "deprecated": true}
+            ]}"#,
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+
+        // Deprecated entries load normally by default
+        let result = known_values::load_from_config(&config);
+        assert_eq!(result.values_count(), 2);
+        assert!(result.values[&75002].is_deprecated());
+        assert!(!result.values[&75001].is_deprecated());
+
+        // and are skipped when requested
+        let result =
+            known_values::load_from_config(&config.skip_deprecated(true));
+        assert!(!result.has_errors());
+        assert_eq!(result.values_count(), 1);
+        assert!(result.values.contains_key(&75001));
+    }
+
+    #[test]
+    fn test_empty_files_skipped_by_default() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("empty.json"), "").unwrap();
+        std::fs::write(temp_dir.path().join("blank.json"), "  \n\t\n").unwrap();
+        std::fs::write(
+            temp_dir.path().join("valid.json"),
+            r#"{"entries": [{"codepoint": 76001, "name": "besideEmpty"}]}"#,
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let result = known_values::load_from_config(&config);
+        assert!(!result.has_errors());
+        assert!(result.values.contains_key(&76001));
+
+        let mut store = KnownValuesStore::default();
+        assert_eq!(store.load_from_directory(temp_dir.path()).unwrap(), 1);
+
+        // Reported as errors when skipping is disabled
+        let result =
+            known_values::load_from_config(&config.skip_empty_files(false));
+        assert_eq!(result.errors.len(), 2);
+        assert!(
+            result
+                .errors
+                .iter()
+                .all(|(_, e)| matches!(e, LoadError::EmptyFile { .. }))
+        );
+        assert!(result.values.contains_key(&76001));
+    }
+
+    #[test]
+    fn test_load_report_json() {
+        let first = TempDir::new().unwrap();
+        let second = TempDir::new().unwrap();
+        std::fs::write(
+            first.path().join("base.json"),
+            r#"{
+                "ontology": {"name": "base_registry", "start_code_point": 77000},
+                "entries": [{"codepoint": 77001, "name": "original"}]
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            second.path().join("override.json"),
+            r#"[{"codepoint": 77001, "name": "replacement"}]"#,
+        )
+        .unwrap();
+        std::fs::write(second.path().join("broken.json"), "{ broken }")
+            .unwrap();
+
+        let config = DirectoryConfig::with_paths(vec![
+            first.path().to_path_buf(),
+            second.path().to_path_buf(),
+        ]);
+        let report = known_values::load_from_config(&config).to_json();
+
+        assert_eq!(report["counts"]["values"], 1);
+        assert_eq!(report["counts"]["files_processed"], 2);
+        assert_eq!(report["counts"]["errors"], 1);
+        assert_eq!(report["files_processed"].as_array().unwrap().len(), 2);
+
+        let ontology = &report["ontologies"][0];
+        assert!(ontology["file"].as_str().unwrap().ends_with("base.json"));
+        assert_eq!(ontology["name"], "base_registry");
+        assert_eq!(ontology["start_code_point"], 77000);
+
+        let value_override = &report["overrides"][0];
+        assert_eq!(value_override["codepoint"], 77001);
+        assert_eq!(value_override["kept"], "replacement");
+        assert_eq!(value_override["discarded"], "original");
+
+        let error = &report["errors"][0];
+        assert!(error["path"].as_str().unwrap().ends_with("broken.json"));
+        assert!(error["message"].as_str().unwrap().contains("broken.json"));
+    }
+
+    #[test]
+    fn test_directory_config_from_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.json");
+        std::fs::write(
+            &config_path,
+            r#"{
+                "paths": ["/etc/known-values", "~/.known-values"],
+                "merge_strategy": "first_wins",
+                "fail_fast": true,
+                "strict": true,
+                "expand_paths": true,
+                "skip_empty_files": false
+            }"#,
+        )
+        .unwrap();
+
+        let config = DirectoryConfig::from_file(&config_path).unwrap();
+        assert_eq!(
+            config.paths(),
+            &[
+                Path::new("/etc/known-values").to_path_buf(),
+                Path::new("~/.known-values").to_path_buf(),
+            ]
+        );
+        assert_eq!(config.get_merge_strategy(), MergeStrategy::FirstWins);
+        assert!(config.is_fail_fast());
+        assert!(config.is_strict());
+        assert!(config.is_expand_paths());
+        assert!(!config.is_skip_deprecated());
+        assert!(!config.is_skip_empty_files());
+
+        // Omitted fields take their defaults
+        std::fs::write(&config_path, "{}").unwrap();
+        let config = DirectoryConfig::from_file(&config_path).unwrap();
+        assert!(config.paths().is_empty());
+        assert_eq!(config.get_merge_strategy(), MergeStrategy::LastWins);
+        assert!(config.is_skip_empty_files());
+
+        // Unknown fields and missing files are rejected
+        std::fs::write(&config_path, r#"{"recurse": true}"#).unwrap();
+        assert!(matches!(
+            DirectoryConfig::from_file(&config_path),
+            Err(ConfigError::InvalidConfigFile { .. })
+        ));
+        assert!(matches!(
+            DirectoryConfig::from_file(temp_dir.path().join("missing.json")),
+            Err(ConfigError::InvalidConfigFile { .. })
+        ));
+    }
+
+    #[test]
+    fn test_merge_from_honors_strategy() {
+        let first = TempDir::new().unwrap();
+        let second = TempDir::new().unwrap();
+        std::fs::write(
+            first.path().join("a.json"),
+            r#"[{"codepoint": 78001, "name": "fromFirst"},
+                {"codepoint": 78002, "name": "onlyFirst"}]"#,
+        )
+        .unwrap();
+        std::fs::write(
+            second.path().join("b.json"),
+            r#"[{"codepoint": 78001, "name": "fromSecond"}]"#,
+        )
+        .unwrap();
+        std::fs::write(second.path().join("broken.json"), "{").unwrap();
+
+        let load = |dir: &TempDir| {
+            known_values::load_from_config(&DirectoryConfig::with_paths(vec![
+                dir.path().to_path_buf(),
+            ]))
+        };
+
+        for (strategy, expected) in [
+            (MergeStrategy::LastWins, "fromSecond"),
+            (MergeStrategy::FirstWins, "fromFirst"),
+        ] {
+            let mut result = load(&first);
+            result.merge_from(load(&second), strategy);
+
+            assert_eq!(result.values.len(), 2);
+            assert_eq!(result.values[&78001].name(), expected);
+            assert_eq!(result.values[&78002].name(), "onlyFirst");
+            assert_eq!(result.files_processed.len(), 2);
+            assert_eq!(result.errors.len(), 1);
+            assert_eq!(result.overrides.len(), 1);
+            assert_eq!(result.overrides[0].kept.name(), expected);
+        }
+    }
+
+    #[test]
+    fn test_duplicate_names_across_codepoints() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("dups.json"),
+            r#"[{"codepoint": 79001, "name": "shared"},
+                {"codepoint": 79002, "name": "shared"}]"#,
+        )
+        .unwrap();
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+
+        // Allowed by default: both load, but a store keeps the name for only
+        // one of them
+        let result = known_values::load_from_config(&config);
+        assert!(!result.has_errors());
+        assert_eq!(result.values.len(), 2);
+        let store = KnownValuesStore::new(result.values.into_values());
+        let named = store.known_value_named("shared").unwrap().value();
+        let unnamed = if named == 79001 { 79002 } else { 79001 };
+        assert_eq!(store.assigned_name(&KnownValue::new(unnamed)), None);
+
+        // Detected when disallowed: the later codepoint is skipped
+        let config = config.allow_duplicate_names(false);
+        let result = known_values::load_from_config(&config);
+        assert_eq!(result.values.len(), 1);
+        assert!(result.values.contains_key(&79001));
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0].1,
+            LoadError::DuplicateName {
+                name,
+                codepoint_a: 79001,
+                codepoint_b: 79002,
+            } if name == "shared"
+        ));
+        assert!(result.errors[0].0.ends_with("dups.json"));
+
+        let err = known_values::try_load_from_config(&config.fail_fast(true))
+            .unwrap_err();
+        assert!(matches!(err, LoadError::DuplicateName { .. }));
+    }
+
+    #[cfg(feature = "archive")]
+    #[test]
+    fn test_load_from_archive() {
+        use std::io::Write;
+
+        let temp_dir = TempDir::new().unwrap();
+        let archive_path = temp_dir.path().join("registries.zip");
+        let mut writer =
+            zip::ZipWriter::new(std::fs::File::create(&archive_path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, content) in [
+            (
+                "core/first.json",
+                r#"{"ontology": {"name": "first"},
+                    "entries": [{"codepoint": 80001, "name": "fromFirst"}]}"#,
+            ),
+            (
+                "second.json",
+                r#"[{"codepoint": 80002, "name": "fromSecond"}]"#,
+            ),
+            ("README.txt", "not a registry"),
+        ] {
+            writer.start_file(name, options).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let result = known_values::load_from_archive(&archive_path);
+        assert!(!result.has_errors());
+        assert_eq!(result.values.len(), 2);
+        assert_eq!(result.values[&80001].name(), "fromFirst");
+        assert_eq!(result.values[&80002].name(), "fromSecond");
+        assert_eq!(result.files_processed, vec![archive_path]);
+        assert_eq!(result.ontologies.len(), 1);
+        assert_eq!(result.ontologies[0].0, Path::new("core/first.json"));
+
+        let missing = known_values::load_from_archive(
+            &temp_dir.path().join("missing.zip"),
+        );
+        assert!(missing.has_errors());
+        assert!(missing.values.is_empty());
+    }
+
+    #[test]
+    fn test_localized_names() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("localized.json"),
+            r#"{"entries": [{
+                "codepoint": 81001,
+                "name": "color",
+                "names_by_lang": {"en": "color", "en-GB": "colour"}
+            }]}"#,
+        )
+        .unwrap();
+
+        let mut store = KnownValuesStore::default();
+        store.load_from_directory(temp_dir.path()).unwrap();
+
+        assert_eq!(store.name_localized(81001, "en-GB"), Some("colour"));
+        assert_eq!(store.name_localized(81001, "en"), Some("color"));
+        assert_eq!(
+            store.name_localized(81001, "en-GB-oxendict"),
+            Some("colour")
+        );
+        assert_eq!(store.name_localized(81001, "en-US"), Some("color"));
+        assert_eq!(store.name_localized(81001, "fr"), Some("color"));
+        assert_eq!(store.name_localized(81002, "en"), None);
+    }
+
+    #[test]
+    fn test_processing_strategy_from_load_result() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("generated.json");
+        std::fs::write(
+            &file_path,
+            r#"{
+                "ontology": {
+                    "name": "generated",
+                    "processing_strategy": "automated_extraction"
+                },
+                "entries": [{"codepoint": 82001, "name": "extracted"}]
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("plain.json"),
+            r#"[{"codepoint": 82002, "name": "plain"}]"#,
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let result = known_values::load_from_config(&config);
+
+        assert_eq!(
+            result.processing_strategy(&file_path),
+            Some("automated_extraction")
+        );
+        assert_eq!(
+            result.ontology(&file_path).unwrap().name.as_deref(),
+            Some("generated")
+        );
+        assert_eq!(
+            result.processing_strategy(&temp_dir.path().join("plain.json")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_search_path_is_a_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("registry.json");
+        std::fs::write(
+            &file_path,
+            r#"{"entries": [{"codepoint": 79001, "name": "misplaced"}]}"#,
+        )
+        .unwrap();
+
+        let config = DirectoryConfig::with_paths(vec![file_path.clone()]);
+        let result = known_values::load_from_config(&config);
+        assert!(result.values.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].0, file_path);
+        assert!(matches!(
+            &result.errors[0].1,
+            LoadError::NotADirectory { path } if *path == file_path
+        ));
+
+        // Without strict mode the path is only a warning
+        let result = known_values::try_load_from_config(&config).unwrap();
+        assert!(matches!(
+            result.errors[0].1,
+            LoadError::NotADirectory { .. }
+        ));
+
+        let strict = config.clone().strict(true);
+        assert!(matches!(
+            known_values::try_load_from_config(&strict),
+            Err(LoadError::NotADirectory { .. })
+        ));
+        assert_eq!(known_values::load_from_config(&strict).errors.len(), 1);
+        assert!(matches!(
+            known_values::try_load_from_config(&config.fail_fast(true)),
+            Err(LoadError::NotADirectory { .. })
+        ));
+
+        // The standalone loaders still treat a file as an empty directory
+        assert!(
+            known_values::load_from_directory(&file_path)
+                .unwrap()
+                .is_empty()
+        );
+        let mut store = KnownValuesStore::default();
+        assert_eq!(store.load_from_directory(&file_path).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_force_reconfigure_and_reload() {
+        let _guard = GLOBAL_RELOAD.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("reloaded.json"),
+            r#"{"entries": [{"codepoint": 80001, "name": "reloadedValue"}]}"#,
+        )
+        .unwrap();
+
+        let _ = known_values::set_directory_config(DirectoryConfig::new());
+        {
+            let binding = KNOWN_VALUES.get();
+            let store = binding.as_ref().unwrap();
+            assert!(store.known_value_named("reloadedValue").is_none());
+        }
+        assert!(known_values::is_initialized());
+
+        known_values::force_set_directory_config(DirectoryConfig::with_paths(
+            vec![temp_dir.path().to_path_buf()],
+        ))
+        .unwrap();
+        let result = known_values::reload_known_values().unwrap();
+        assert!(!result.has_errors());
+        {
+            let binding = KNOWN_VALUES.get();
+            let store = binding.as_ref().unwrap();
+            assert_eq!(
+                store.known_value_named("reloadedValue").unwrap().value(),
+                80001
+            );
+            assert_eq!(store.known_value_named("isA").unwrap().value(), 1);
+        }
+
+        known_values::force_set_directory_config(DirectoryConfig::new())
+            .unwrap();
+        known_values::reload_known_values().unwrap();
+        let binding = KNOWN_VALUES.get();
+        let store = binding.as_ref().unwrap();
+        assert!(store.known_value_named("reloadedValue").is_none());
+    }
+
+    #[test]
+    fn test_manifest_controls_files_and_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let write = |file: &str, name: &str| {
+            std::fs::write(
+                temp_dir.path().join(file),
+                format!(
+                    r#"{{"entries": [{{"codepoint": 81001, "name": "{name}"}}]}}"#
+                ),
+            )
+            .unwrap();
+        };
+        write("a.json", "fromA");
+        write("b.json", "fromB");
+        write("staged.json", "fromStaged");
+        std::fs::write(
+            temp_dir.path().join("index.json"),
+            r#"{"files": ["b.json", "a.json"]}"#,
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()])
+                .use_manifest("index.json");
+        assert_eq!(config.manifest(), Some("index.json"));
+
+        let result = known_values::load_from_config(&config);
+        assert!(!result.has_errors());
+        // Later files win, so the manifest order decides the name
+        assert_eq!(result.values[&81001].name(), "fromA");
+        assert_eq!(result.values.len(), 1);
+
+        let overrides: Vec<_> = result
+            .overrides
+            .iter()
+            .map(|o| (o.kept.name(), o.discarded.name()))
+            .collect();
+        assert_eq!(overrides, vec![("fromA".to_string(), "fromB".to_string())]);
+    }
+
+    #[test]
+    fn test_manifest_listing_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("present.json"),
+            r#"{"entries": [{"codepoint": 81101, "name": "present"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("index.json"),
+            r#"{"files": ["missing.json", "present.json"]}"#,
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()])
+                .use_manifest("index.json");
+        let result = known_values::load_from_config(&config);
+
+        // The missing file is reported, and the rest of the manifest loads
+        assert_eq!(result.values[&81101].name(), "present");
+        assert_eq!(result.errors.len(), 1);
+        let (path, error) = &result.errors[0];
+        assert_eq!(*path, temp_dir.path().join("missing.json"));
+        assert!(matches!(
+            error,
+            LoadError::Io(e) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+
+        let fail_fast = config.fail_fast(true);
+        assert!(matches!(
+            known_values::try_load_from_config(&fail_fast),
+            Err(LoadError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_manifest_rejects_escaping_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let registry = temp_dir.path().join("registry");
+        std::fs::create_dir(&registry).unwrap();
+        std::fs::write(
+            temp_dir.path().join("outside.json"),
+            r#"{"entries": [{"codepoint": 81201, "name": "outside"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            registry.join("inside.json"),
+            r#"{"entries": [{"codepoint": 81202, "name": "inside"}]}"#,
+        )
+        .unwrap();
+
+        let entries = [
+            PathBuf::from("../outside.json"),
+            PathBuf::from("nested/../../outside.json"),
+            temp_dir.path().join("outside.json"),
+        ];
+        for entry in entries {
+            std::fs::write(
+                registry.join("index.json"),
+                format!(
+                    r#"{{"files": ["inside.json", {:?}]}}"#,
+                    entry.to_str().unwrap()
+                ),
+            )
+            .unwrap();
+
+            let config = DirectoryConfig::with_paths(vec![registry.clone()])
+                .use_manifest("index.json");
+            let result = known_values::load_from_config(&config);
+
+            // Nothing is loaded from a directory with an invalid manifest
+            assert!(result.values.is_empty());
+            assert_eq!(result.errors.len(), 1);
+            assert!(matches!(
+                &result.errors[0].1,
+                LoadError::InvalidManifestEntry { manifest, entry: rejected }
+                    if *manifest == registry.join("index.json")
+                        && *rejected == entry
+            ));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unreadable_directory_is_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let locked = temp_dir.path().join("locked");
+        std::fs::create_dir(&locked).unwrap();
+        std::fs::write(
+            locked.join("registry.json"),
+            r#"{"entries": [{"codepoint": 82001, "name": "hidden"}]}"#,
+        )
+        .unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0))
+            .unwrap();
+
+        // Privileged users bypass permission checks
+        let readable = std::fs::read_dir(&locked).is_ok();
+        let config = DirectoryConfig::with_paths(vec![locked.clone()]);
+        let result = known_values::load_from_config(&config);
+        let strict =
+            known_values::try_load_from_config(&config.fail_fast(true));
+        std::fs::set_permissions(
+            &locked,
+            std::fs::Permissions::from_mode(0o755),
+        )
+        .unwrap();
+        if readable {
+            return;
+        }
+
+        assert!(result.values.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert!(matches!(
+            &result.errors[0].1,
+            LoadError::PermissionDenied { path } if *path == locked
+        ));
+        assert!(matches!(strict, Err(LoadError::PermissionDenied { .. })));
+    }
+
+    #[test]
+    fn test_known_value_by_uri() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("uris.json"),
+            r#"{"entries": [
+                {"codepoint": 83001, "name": "color",
+                 "uri": "https://example.com/vocab#color"},
+                {"codepoint": 83002, "name": "size",
+                 "uri": "https://example.com/vocab#size"},
+                {"codepoint": 83003, "name": "noUri"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let mut store = KnownValuesStore::default();
+        assert_eq!(store.load_from_directory(temp_dir.path()).unwrap(), 3);
+        let size = store
+            .known_value_by_uri("https://example.com/vocab#size")
+            .unwrap();
+        assert_eq!(size.value(), 83002);
+        assert_eq!(size.name(), "size");
+        assert!(
+            store
+                .known_value_by_uri("https://example.com/vocab#weight")
+                .is_none()
+        );
+
+        // Replacing or removing a value drops its URI from the index
+        store.insert(KnownValue::new_with_name(83002u64, "size".to_string()));
+        assert!(
+            store
+                .known_value_by_uri("https://example.com/vocab#size")
+                .is_none()
+        );
+        let color = store
+            .known_value_by_uri("https://example.com/vocab#color")
+            .unwrap()
+            .clone();
+        store.remove(&color);
+        assert!(
+            store
+                .known_value_by_uri("https://example.com/vocab#color")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_error_handler_called_for_each_error() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("bad1.json"), "{ not json")
+            .unwrap();
+        std::fs::write(temp_dir.path().join("bad2.json"), "[1, 2").unwrap();
+        std::fs::write(
+            temp_dir.path().join("good.json"),
+            r#"{"entries": [{"codepoint": 84001, "name": "handled"}]}"#,
+        )
+        .unwrap();
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let handler_count = Arc::clone(&count);
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()])
+                .with_error_handler(Box::new(move |path, error| {
+                    assert!(
+                        path.ends_with("bad1.json")
+                            || path.ends_with("bad2.json")
+                    );
+                    assert!(matches!(error, LoadError::Json { .. }));
+                    handler_count.fetch_add(1, Ordering::SeqCst);
+                }));
+
+        let result = known_values::load_from_config(&config);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.values[&84001].name(), "handled");
+    }
+
+    #[test]
+    fn test_reload_keeps_previous_registry_on_failure() {
+        let _guard = GLOBAL_RELOAD.lock().unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("good.json"),
+            r#"{"entries": [{"codepoint": 85001, "name": "beforeReload"}]}"#,
+        )
+        .unwrap();
+        let config =
+            || DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let named = |name: &str| {
+            let binding = KNOWN_VALUES.get();
+            binding.as_ref().unwrap().codepoint_of(name)
+        };
+
+        known_values::force_set_directory_config(config()).unwrap();
+        known_values::reload_known_values().unwrap();
+        assert_eq!(named("beforeReload"), Some(85001));
+
+        // A bad deploy: the good file is replaced and a broken one appears
+        std::fs::write(
+            temp_dir.path().join("good.json"),
+            r#"{"entries": [{"codepoint": 85002, "name": "afterReload"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("broken.json"), "{ oops").unwrap();
+
+        let error = known_values::reload_known_values().unwrap_err();
+        assert_eq!(error.max_errors, 0);
+        assert_eq!(error.result.errors.len(), 1);
+        assert_eq!(named("beforeReload"), Some(85001));
+        assert_eq!(named("afterReload"), None);
+
+        // Tolerating one error lets the reload through
+        known_values::force_set_directory_config(config().max_reload_errors(1))
+            .unwrap();
+        let result = known_values::reload_known_values().unwrap();
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(named("beforeReload"), None);
+        assert_eq!(named("afterReload"), Some(85002));
+
+        known_values::force_set_directory_config(DirectoryConfig::new())
+            .unwrap();
+        known_values::reload_known_values().unwrap();
+    }
+
+    #[test]
+    fn test_merge_standalone_result_keep_existing() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("values.json"),
+            r#"{"entries": [
+                {"codepoint": 1, "name": "type"},
+                {"codepoint": 86000, "name": "merged"},
+                {"codepoint": 86001, "name": "alsoMerged"}
+            ]}"#,
+        )
+        .unwrap();
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let result = known_values::load_from_config(&config);
+        assert_eq!(result.values_count(), 3);
+
+        let mut store = KnownValuesStore::new([IS_A, NOTE]);
+        let summary = store.merge_result(&result, MergeStrategy::FirstWins);
+        assert_eq!(summary.added, 2);
+        assert_eq!(summary.overwritten, 0);
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(store.name_of(1), Some("isA"));
+        assert_eq!(store.codepoint_of("type"), None);
+        assert_eq!(store.codepoint_of("merged"), Some(86000));
+        assert_eq!(store.codepoint_of("alsoMerged"), Some(86001));
+
+        let summary = store.merge_result(&result, MergeStrategy::LastWins);
+        assert_eq!(summary.added, 0);
+        assert_eq!(summary.overwritten, 3);
+        assert_eq!(summary.skipped, 0);
+        assert_eq!(store.name_of(1), Some("type"));
+    }
+
+    #[test]
+    fn test_bom_and_invalid_utf8_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let bom_path = temp_dir.path().join("bom.json");
+        let mut content = b"\xEF\xBB\xBF".to_vec();
+        content.extend_from_slice(
+            br#"{"entries": [{"codepoint": 87000, "name": "withBom"}]}"#,
+        );
+        std::fs::write(&bom_path, content).unwrap();
+
+        let values =
+            known_values::load_from_directory(temp_dir.path()).unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].name(), "withBom");
+
+        let latin1_path = temp_dir.path().join("latin1.json");
+        std::fs::write(
+            &latin1_path,
+            b"{\"entries\": [{\"codepoint\": 87001, \"name\": \"caf\xE9\"}]}",
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let result = known_values::load_from_config(&config);
+        assert_eq!(result.values_count(), 1);
+        assert_eq!(result.errors.len(), 1);
+        let (path, error) = &result.errors[0];
+        assert_eq!(*path, latin1_path);
+        assert!(matches!(
+            error,
+            LoadError::Encoding { file } if *file == latin1_path
+        ));
+    }
+
+    #[test]
+    fn test_lenient_json_config() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("commas.json"),
+            r#"{"entries": [{"codepoint": 88000, "name": "lenient",},],}"#,
+        )
+        .unwrap();
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        assert!(!config.is_lenient_json());
+
+        let strict = known_values::load_from_config(&config);
+        assert_eq!(strict.values_count(), 0);
+        assert!(matches!(strict.errors[0].1, LoadError::Json { .. }));
+
+        let lenient =
+            known_values::load_from_config(&config.lenient_json(true));
+        assert!(!lenient.has_errors());
+        assert_eq!(lenient.values[&88000].name(), "lenient");
+    }
+
+    #[test]
+    fn test_load_result_counters() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("a.json"),
+            r#"{"entries": [
+                {"codepoint": 89000, "name": "kept"},
+                {"codepoint": 89001, "name": "old", "deprecated": true},
+                {"codepoint": 89002, "name": "kept"}
+            ]}"#,
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("broken.json"), "{ oops").unwrap();
+        std::fs::write(temp_dir.path().join("README.md"), "# Notes").unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), "").unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()])
+                .skip_deprecated(true)
+                .allow_duplicate_names(false);
+        let result = known_values::load_from_config(&config);
+
+        assert_eq!(result.files_scanned, 4);
+        assert_eq!(result.files_parsed, 1);
+        assert_eq!(result.entries_loaded, 1);
+        assert_eq!(result.entries_skipped, 2);
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(result.to_json()["counts"]["entries_skipped"], 2);
+
+        let permissive =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let result = known_values::load_from_config(&permissive);
+        assert_eq!(result.entries_loaded, 3);
+        assert_eq!(result.entries_skipped, 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks() {
+        let target_dir = TempDir::new().unwrap();
+        let target = target_dir.path().join("shared.json");
+        std::fs::write(
+            &target,
+            r#"{"entries": [{"codepoint": 90000, "name": "linked"}]}"#,
+        )
+        .unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("local.json"),
+            r#"{"entries": [{"codepoint": 90001, "name": "local"}]}"#,
+        )
+        .unwrap();
+        std::os::unix::fs::symlink(&target, temp_dir.path().join("link.json"))
+            .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        assert!(config.is_follow_symlinks());
+        let followed = known_values::load_from_config(&config);
+        assert!(!followed.has_errors());
+        assert_eq!(followed.values[&90000].name(), "linked");
+        assert_eq!(followed.values[&90001].name(), "local");
+
+        let skipped =
+            known_values::load_from_config(&config.follow_symlinks(false));
+        assert!(!skipped.has_errors());
+        assert!(!skipped.values.contains_key(&90000));
+        assert_eq!(skipped.values[&90001].name(), "local");
+        assert_eq!(skipped.files_scanned, 2);
+        assert_eq!(skipped.files_parsed, 1);
+    }
+
+    #[test]
+    fn test_load_result_into_store() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("registry.json"),
+            r#"{"entries": [
+                {"codepoint": 90100, "name": "first"},
+                {"codepoint": 90101, "name": "second"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let config =
+            DirectoryConfig::with_paths(vec![temp_dir.path().to_path_buf()]);
+        let result = known_values::load_from_config(&config);
+        assert!(!result.has_errors());
+
+        let store = result.into_store();
+        assert_eq!(store.codepoints_sorted(), vec![90100, 90101]);
+        assert_eq!(store.codepoint_of("first"), Some(90100));
+        assert_eq!(store.name_of(90101), Some("second"));
+        assert!(store.known_value_named("isA").is_none());
+    }
+
+    #[test]
+    fn test_entry_transform_offsets_codepoints() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("third_party.json"),
+            r#"{"entries": [
+                {"codepoint": 1, "name": "widget"},
+                {"codepoint": 2, "name": "gadget"},
+                {"codepoint": 3, "name": "obsolete"}
+            ]}"#,
+        )
+        .unwrap();
+
+        const OFFSET: u64 = 100_000;
+        let config = DirectoryConfig::builder()
+            .path(temp_dir.path())
+            .with_entry_transform(Box::new(|mut entry| {
+                if entry.name == "obsolete" {
+                    return None;
+                }
+                entry.codepoint += OFFSET;
+                Some(entry)
+            }))
+            .build();
+        assert!(config.has_entry_transform());
+
+        let result = known_values::load_from_config(&config);
+        assert!(!result.has_errors());
+        assert_eq!(result.values_count(), 2);
+        assert_eq!(result.values[&(OFFSET + 1)].name(), "widget");
+        assert_eq!(result.values[&(OFFSET + 2)].name(), "gadget");
+        assert!(!result.values.contains_key(&1));
+        assert_eq!(result.entries_loaded, 2);
+    }
 }